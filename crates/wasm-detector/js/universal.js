@@ -0,0 +1,22 @@
+// Runtime-agnostic entry point for Deno, Bun and Cloudflare Workers.
+//
+// The default wasm-bindgen glue locates the .wasm file relative to
+// `import.meta.url` and fetches it, which fails in runtimes without a
+// file-backed module URL. This wrapper requires the caller to hand over the
+// wasm bytes (or an already compiled WebAssembly.Module) explicitly.
+
+import wasmInit from "./wasm_detector.js";
+
+export * from "./wasm_detector.js";
+
+let ready = null;
+
+export function init(wasmBytes) {
+    if (!wasmBytes) {
+        throw new TypeError(
+            "init(wasmBytes) expects the wasm binary or a WebAssembly.Module",
+        );
+    }
+    ready ??= wasmInit({ module_or_path: wasmBytes });
+    return ready;
+}
@@ -0,0 +1,75 @@
+use crate::collect_matches;
+use crate::compare::compare_key;
+use serde::Serialize;
+
+/// A detected address that nearly, but not exactly, equals a known address.
+#[derive(Serialize)]
+pub struct Lookalike {
+    pub value: String,
+    pub index: usize,
+    pub type_: String,
+    pub rule_id: String,
+    /// The address-book entry this value resembles.
+    pub known: String,
+    pub distance: usize,
+}
+
+/// Levenshtein distance, giving up once it is certain to exceed `max`.
+fn edit_distance(a: &str, b: &str, max: usize) -> Option<usize> {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    if a.len().abs_diff(b.len()) > max {
+        return None;
+    }
+
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut row = vec![0; b.len() + 1];
+    for (i, ca) in a.iter().enumerate() {
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = prev[j] + usize::from(ca != cb);
+            row[j + 1] = substitution.min(prev[j + 1] + 1).min(row[j] + 1);
+        }
+        if row.iter().min().is_some_and(|&d| d > max) {
+            return None;
+        }
+        std::mem::swap(&mut prev, &mut row);
+    }
+    Some(prev[b.len()]).filter(|&d| d <= max)
+}
+
+/// Flags detected addresses within `max_distance` edits of an address-book
+/// entry without being identical to it — the signature of address poisoning,
+/// where an attacker plants a lookalike hoping it gets copied. Each match
+/// reports its closest known entry. Transaction hashes, names and other
+/// non-address matches are never compared.
+pub fn find_lookalikes(text: &str, book: &[String], max_distance: usize) -> Vec<Lookalike> {
+    collect_matches(text)
+        .into_iter()
+        .filter(|m| m.kind == "address")
+        .filter_map(|m| {
+            // Book entries are keyed as if they were this match's kind of value.
+            let keyed: Vec<(String, &String)> = book
+                .iter()
+                .map(|k| (compare_key(&m.rule_id, k), k))
+                .collect();
+            let value = &m.compare_key;
+            if keyed.iter().any(|(k, _)| k == value) {
+                return None;
+            }
+            let (distance, known) = keyed
+                .iter()
+                .filter_map(|(k, known)| Some((edit_distance(value, k, max_distance)?, *known)))
+                .min_by_key(|(d, _)| *d)?;
+
+            Some(Lookalike {
+                value: m.value,
+                index: m.index,
+                type_: m.type_,
+                rule_id: m.rule_id,
+                known: known.clone(),
+                distance,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,80 @@
+//! k-anonymous aggregates for opt-in telemetry. Only per-type counts leave
+//! the crate, and a count below `k` is suppressed so a rare type cannot
+//! single out the page or user it came from. Raw values are never retained.
+
+use crate::{DetectorError, DetectorOptions, Match, collect_matches_with_options};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Smallest count reported when the caller does not choose one.
+pub const DEFAULT_K: usize = 5;
+
+/// A summary safe to send off-device.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct AggregateSummary {
+    pub k: usize,
+    pub scans: usize,
+    /// Match counts per type, only for types seen at least `k` times.
+    pub counts: BTreeMap<String, usize>,
+    /// Combined count of the suppressed types, when that total itself
+    /// reaches `k`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub other: Option<usize>,
+}
+
+/// Accumulates per-type counts across scans.
+#[derive(Debug, Default, Clone)]
+pub struct Aggregator {
+    counts: BTreeMap<String, usize>,
+    scans: usize,
+}
+
+impl Aggregator {
+    pub fn new() -> Self {
+        Aggregator::default()
+    }
+
+    /// Counts one scan's matches; only their types are kept.
+    pub fn add(&mut self, matches: &[Match]) {
+        self.scans += 1;
+        for m in matches {
+            *self.counts.entry(m.type_.clone()).or_default() += m.starts().len();
+        }
+    }
+
+    /// Scans `text` and counts the result without returning any values.
+    pub fn add_text(&mut self, text: &str, options: &DetectorOptions) -> Result<(), DetectorError> {
+        self.add(&collect_matches_with_options(text, options)?);
+        Ok(())
+    }
+
+    /// The counts with every type below `k` suppressed. `k` is at least 1.
+    pub fn summary(&self, k: usize) -> AggregateSummary {
+        let k = k.max(1);
+        let (kept, suppressed): (BTreeMap<_, _>, BTreeMap<_, _>) = self
+            .counts
+            .iter()
+            .map(|(t, &n)| (t.clone(), n))
+            .partition(|&(_, n)| n >= k);
+        let other: usize = suppressed.values().sum();
+        AggregateSummary {
+            k,
+            scans: self.scans,
+            counts: kept,
+            other: (other >= k).then_some(other),
+        }
+    }
+}
+
+/// Scans every text and returns only the k-anonymous summary.
+pub fn aggregate_texts(
+    texts: &[String],
+    options: &DetectorOptions,
+    k: usize,
+) -> Result<AggregateSummary, DetectorError> {
+    let mut aggregator = Aggregator::new();
+    for text in texts {
+        aggregator.add_text(text, options)?;
+    }
+    Ok(aggregator.summary(k))
+}
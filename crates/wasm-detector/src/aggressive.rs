@@ -0,0 +1,92 @@
+//! Opt-in aggressive mode: every pattern is re-run with its `\b` anchors
+//! removed to catch values glued to neighbouring text (`address:0x…`,
+//! `walletBc1q…`). Such hits are noisier, so each carries a `confidence`
+//! score in its metadata.
+
+use crate::{Match, PATTERNS, Pattern, find_overlap, validators};
+use regex::Regex;
+use std::sync::OnceLock;
+
+static UNANCHORED: OnceLock<Vec<OnceLock<Regex>>> = OnceLock::new();
+
+/// The pattern's regex with word-boundary anchors stripped, compiled on first use.
+fn unanchored(pattern: &Pattern) -> &'static Regex {
+    let table = UNANCHORED.get_or_init(|| PATTERNS.iter().map(|_| OnceLock::new()).collect());
+    table[pattern.position()]
+        .get_or_init(|| Regex::new(&pattern.source.replace(r"\b", "")).unwrap())
+}
+
+fn is_word_char(c: Option<char>) -> bool {
+    c.is_some_and(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// Starts at 0.9 and loses 0.2 for each side glued to a word character and
+/// 0.2 more when the pattern has no checksum or validator to confirm it.
+fn confidence(text: &str, start: usize, end: usize, pattern: &Pattern) -> f32 {
+    let glued_before = is_word_char(text[..start].chars().next_back());
+    let glued_after = is_word_char(text[end..].chars().next());
+    let mut score = 0.9 - 0.2 * (glued_before as u8 + glued_after as u8) as f32;
+    if pattern.validate.is_none() {
+        score -= 0.2;
+    }
+    score.max(0.1)
+}
+
+/// Glued suffix characters that may be trimmed from a candidate, e.g. a
+/// camelCase word that continues in the same alphabet as the value.
+const MAX_TRIM: usize = 8;
+
+/// End of the longest prefix of the candidate `[start, end)` that is still a
+/// full match of the pattern and passes its validator.
+fn accepted_end(
+    text: &str,
+    start: usize,
+    end: usize,
+    pattern: &Pattern,
+    re: &Regex,
+) -> Option<usize> {
+    (0..=MAX_TRIM)
+        .filter_map(|trim| end.checked_sub(trim))
+        .filter(|&end| end > start && text.is_char_boundary(end))
+        .find(|&end| {
+            let value = &text[start..end];
+            re.find(value).is_some_and(|m| m.len() == value.len()) && pattern.accepts(value)
+        })
+}
+
+/// Finds values missed by the anchored pass, skipping spans already claimed.
+pub(crate) fn find_glued(
+    text: &str,
+    patterns: &[&'static Pattern],
+    matches: &[Match],
+) -> Vec<Match> {
+    let mut found: Vec<Match> = Vec::new();
+    for pattern in patterns {
+        let re = unanchored(pattern);
+        let mut pos = 0;
+        while let Some(cap) = re.find_at(text, pos) {
+            let start = cap.start();
+            let end = accepted_end(text, start, cap.end(), pattern, re).filter(|&end| {
+                find_overlap(start, end, matches).is_none()
+                    && find_overlap(start, end, &found).is_none()
+            });
+            let Some(end) = end else {
+                // A glued prefix may have been swallowed into the candidate
+                // (`walletbc1q…`), so retry from the next character.
+                pos = start + text[start..].chars().next().map_or(1, char::len_utf8);
+                continue;
+            };
+            pos = end;
+
+            let value = &text[start..end];
+            let mut m = pattern.to_match(value, start);
+            let score = confidence(text, start, end, pattern);
+            m.metadata
+                .insert("confidence".to_string(), format!("{score:.1}"));
+            if validators::run_validators(&mut m).is_ok() {
+                found.push(m);
+            }
+        }
+    }
+    found
+}
@@ -0,0 +1,27 @@
+use crate::{Match, grapheme};
+
+/// Grapheme clusters of context hashed on each side of a match.
+pub const ANCHOR_CONTEXT: usize = 16;
+
+/// 32-bit FNV-1a of the UTF-8 bytes, as 8 hex digits. Tiny and stable, so
+/// hosts can recompute it without the wasm module if they need to.
+pub fn anchor_hash(context: &str) -> String {
+    let hash = context.bytes().fold(0x811c_9dc5u32, |hash, b| {
+        (hash ^ b as u32).wrapping_mul(0x0100_0193)
+    });
+    format!("{hash:08x}")
+}
+
+/// Attaches `anchor_before`/`anchor_after`: hashes of up to 16 grapheme
+/// clusters preceding and following each match, letting a host re-locate
+/// the match after small edits elsewhere in the text without rescanning.
+pub(crate) fn attach_anchors(text: &str, matches: &mut [Match]) {
+    for m in matches {
+        let before = grapheme::before(text, m.index, ANCHOR_CONTEXT);
+        let after = grapheme::after(text, m.index + m.value.len(), ANCHOR_CONTEXT);
+        m.metadata
+            .insert("anchor_before".to_string(), anchor_hash(before));
+        m.metadata
+            .insert("anchor_after".to_string(), anchor_hash(after));
+    }
+}
@@ -0,0 +1,61 @@
+use crate::{Match, collect_matches};
+use serde::Serialize;
+
+/// A slice of the input: either untouched text or a detected value.
+#[derive(Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Segment {
+    Text {
+        text: String,
+    },
+    Match {
+        text: String,
+        #[serde(rename = "match")]
+        found: Box<Match>,
+    },
+}
+
+/// Escapes the five HTML-significant characters.
+fn escape_html(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Splits `text` into alternating plain and matched segments covering the
+/// whole input, so renderers can highlight matches without slicing offsets
+/// themselves. With `html` set, every segment's `text` is HTML-escaped
+/// (the match's own `value` stays raw).
+pub fn annotate(text: &str, html: bool) -> Vec<Segment> {
+    let render = |s: &str| if html { escape_html(s) } else { s.to_string() };
+
+    let mut segments = Vec::new();
+    let mut cursor = 0;
+    for m in collect_matches(text) {
+        if m.index > cursor {
+            segments.push(Segment::Text {
+                text: render(&text[cursor..m.index]),
+            });
+        }
+        cursor = m.index + m.value.len();
+        segments.push(Segment::Match {
+            text: render(&m.value),
+            found: Box::new(m),
+        });
+    }
+    if cursor < text.len() {
+        segments.push(Segment::Text {
+            text: render(&text[cursor..]),
+        });
+    }
+    segments
+}
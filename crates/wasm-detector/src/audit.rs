@@ -0,0 +1,96 @@
+//! Optional audit trail of masking. Each record carries the match type and a
+//! salted fingerprint of the value instead of the value itself, so a log can
+//! prove a value was scrubbed without retaining it.
+
+use crate::Match;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::sync::RwLock;
+
+/// One audited action on a detected value.
+#[derive(Serialize, Clone)]
+pub struct AuditRecord {
+    /// Milliseconds since the Unix epoch.
+    pub timestamp_ms: u64,
+    pub type_: String,
+    /// Hex of the first 16 bytes of SHA-256(salt || value).
+    pub fingerprint: String,
+    pub action: String,
+}
+
+/// Receives audit records, e.g. to append them to a log file.
+pub trait AuditSink: Send + Sync {
+    fn record(&self, record: AuditRecord);
+}
+
+impl<T: AuditSink + ?Sized> AuditSink for std::sync::Arc<T> {
+    fn record(&self, record: AuditRecord) {
+        (**self).record(record);
+    }
+}
+
+struct Auditor {
+    salt: Vec<u8>,
+    sink: Box<dyn AuditSink>,
+}
+
+static AUDITOR: RwLock<Option<Auditor>> = RwLock::new(None);
+
+/// Salted, truncated SHA-256 of a value. The same salt and value always give
+/// the same fingerprint, so an auditor holding the salt can confirm a
+/// specific value was handled; without it the fingerprint reveals nothing.
+pub fn fingerprint(salt: &[u8], value: &str) -> String {
+    let digest = Sha256::new()
+        .chain_update(salt)
+        .chain_update(value.as_bytes())
+        .finalize();
+    digest[..16].iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// Installs the sink that receives every audit record, replacing any previous one.
+pub fn set_audit_sink(salt: &[u8], sink: Box<dyn AuditSink>) {
+    *AUDITOR.write().unwrap() = Some(Auditor {
+        salt: salt.to_vec(),
+        sink,
+    });
+}
+
+/// Removes the audit sink; auditing becomes a no-op again.
+pub fn clear_audit_sink() {
+    *AUDITOR.write().unwrap() = None;
+}
+
+/// Wall-clock time, unavailable to plain `wasm32-unknown-unknown` builds
+/// (hosts there pass their own timestamp to `record_with_time`).
+fn now_ms() -> u64 {
+    #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+    {
+        use std::time::{SystemTime, UNIX_EPOCH};
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_millis() as u64)
+    }
+    #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+    {
+        0
+    }
+}
+
+/// Records `action` on a value of the given type, stamped with `timestamp_ms`.
+pub fn record_with_time(type_: &str, value: &str, action: &str, timestamp_ms: u64) {
+    let auditor = AUDITOR.read().unwrap();
+    let Some(auditor) = auditor.as_ref() else {
+        return;
+    };
+    auditor.sink.record(AuditRecord {
+        timestamp_ms,
+        type_: type_.to_string(),
+        fingerprint: fingerprint(&auditor.salt, value),
+        action: action.to_string(),
+    });
+}
+
+/// Records `action` on a match, stamped with the current time.
+pub fn record(m: &Match, action: &str) {
+    record_with_time(&m.type_, &m.value, action, now_ms());
+}
@@ -0,0 +1,79 @@
+use crate::Match;
+use crate::compare::{compare_key, folds_case};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashSet};
+
+/// A single accepted finding, identified by its type and exact value.
+#[derive(Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+struct BaselineEntry {
+    type_: String,
+    value: String,
+}
+
+/// Set of known, accepted matches that should not be reported again.
+#[derive(Serialize, Deserialize, Default)]
+pub struct Baseline {
+    entries: BTreeSet<BaselineEntry>,
+    /// `(type, compare key)` of every entry, for matches whose family keeps
+    /// case.
+    #[serde(skip)]
+    keys: HashSet<(String, String)>,
+    /// The same with the key lowercased, for case-insensitive families.
+    #[serde(skip)]
+    folded: HashSet<(String, String)>,
+}
+
+impl Baseline {
+    /// Builds the lookup sets once, so `suppress` is linear in the matches.
+    fn indexed(entries: BTreeSet<BaselineEntry>) -> Self {
+        let mut keys = HashSet::with_capacity(entries.len());
+        let mut folded = HashSet::with_capacity(entries.len());
+        for e in &entries {
+            let key = compare_key("", &e.value);
+            folded.insert((e.type_.clone(), key.to_ascii_lowercase()));
+            keys.insert((e.type_.clone(), key));
+        }
+        Baseline {
+            entries,
+            keys,
+            folded,
+        }
+    }
+
+    pub fn from_matches(matches: &[Match]) -> Self {
+        let entries = matches
+            .iter()
+            .map(|m| BaselineEntry {
+                type_: m.type_.clone(),
+                value: m.value.clone(),
+            })
+            .collect();
+        Baseline::indexed(entries)
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let baseline: Baseline =
+            serde_json::from_str(json).map_err(|e| format!("Invalid baseline: {e}"))?;
+        Ok(Baseline::indexed(baseline.entries))
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+
+    /// Compares keys computed here rather than `m.compare_key`, so matches
+    /// built without one are still suppressed.
+    fn contains(&self, m: &Match) -> bool {
+        let key = compare_key(&m.rule_id, &m.value);
+        if folds_case(&m.rule_id) || key.starts_with("0x") {
+            self.folded.contains(&(m.type_.clone(), key))
+        } else {
+            self.keys.contains(&(m.type_.clone(), key))
+        }
+    }
+
+    /// Removes every match that is already recorded in the baseline.
+    pub fn suppress(&self, matches: Vec<Match>) -> Vec<Match> {
+        matches.into_iter().filter(|m| !self.contains(m)).collect()
+    }
+}
@@ -0,0 +1,44 @@
+use crate::{DetectorError, DetectorOptions, Match, collect_matches_with_options};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/// Caller-chosen identifier of a text node; numbers and strings both work.
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub enum NodeId {
+    Number(u64),
+    Text(String),
+}
+
+impl NodeId {
+    fn key(&self) -> String {
+        match self {
+            NodeId::Number(n) => n.to_string(),
+            NodeId::Text(s) => s.clone(),
+        }
+    }
+}
+
+/// One serialized text node of a DOM subtree.
+#[derive(Deserialize)]
+pub struct NodeText {
+    #[serde(rename = "nodeId")]
+    pub node_id: NodeId,
+    pub text: String,
+}
+
+/// Scans every node's text in one call and returns the matches keyed by node
+/// id, omitting nodes without matches. Indexes are relative to each node's text.
+pub fn collect_matches_batch(
+    nodes: &[NodeText],
+    options: &DetectorOptions,
+) -> Result<BTreeMap<String, Vec<Match>>, DetectorError> {
+    let mut results = BTreeMap::new();
+    for node in nodes {
+        let matches = collect_matches_with_options(&node.text, options)?;
+        if !matches.is_empty() {
+            results.insert(node.node_id.key(), matches);
+        }
+    }
+    Ok(results)
+}
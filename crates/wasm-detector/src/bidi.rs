@@ -0,0 +1,74 @@
+//! Bidirectional-text controls. Text pasted from right-to-left contexts
+//! often carries invisible marks, embeddings and isolates around or inside
+//! an address; they break word boundaries and shift offsets. Scans run on
+//! the text with the controls removed and matches are mapped back onto the
+//! original, where their spans include any controls inside them. An RTL
+//! override (U+202E), which reverses how the following characters are
+//! displayed and is a known address-spoofing trick, is flagged on the match
+//! as `metadata.rtl_override`.
+
+use crate::{Match, is_editable};
+
+/// Explicit directional formatting characters (Unicode bidi algorithm, §2).
+const CONTROLS: &[char] = &[
+    '\u{061C}', '\u{200E}', '\u{200F}', '\u{202A}', '\u{202B}', '\u{202C}', '\u{202D}', '\u{202E}',
+    '\u{2066}', '\u{2067}', '\u{2068}', '\u{2069}',
+];
+
+const RTL_OVERRIDE: char = '\u{202E}';
+
+/// Text with the bidi controls removed, and where each byte came from.
+pub(crate) struct Stripped {
+    pub(crate) text: String,
+    /// Original byte offset of each stripped byte, plus one for the end.
+    offsets: Vec<usize>,
+}
+
+/// Removes bidi controls, or returns `None` when the text has none.
+pub(crate) fn strip(text: &str) -> Option<Stripped> {
+    if !text.contains(CONTROLS) {
+        return None;
+    }
+    let mut stripped = String::with_capacity(text.len());
+    let mut offsets = Vec::with_capacity(text.len() + 1);
+    for (i, c) in text.char_indices() {
+        if CONTROLS.contains(&c) {
+            continue;
+        }
+        stripped.push(c);
+        offsets.extend(i..i + c.len_utf8());
+    }
+    offsets.push(text.len());
+    Some(Stripped {
+        text: stripped,
+        offsets,
+    })
+}
+
+/// Moves matches found in the stripped text back onto `original`.
+/// Editable ranges refer to the original text, so they are re-applied.
+pub(crate) fn restore(
+    original: &str,
+    stripped: &Stripped,
+    matches: Vec<Match>,
+    editable_ranges: &[[usize; 2]],
+) -> Vec<Match> {
+    matches
+        .into_iter()
+        .map(|mut m| {
+            let start = stripped.offsets[m.index];
+            let last = m.index + m.value.len() - 1;
+            let end = stripped.offsets[last] + 1;
+            // Controls right before the value still govern how it renders.
+            let lead = original[..start].trim_end_matches(CONTROLS).len();
+            if original[lead..end].contains(RTL_OVERRIDE) {
+                m.metadata
+                    .insert("rtl_override".to_string(), "true".to_string());
+            }
+            m.index = start;
+            m.value = original[start..end].to_string();
+            m.editable = is_editable(&m, editable_ranges);
+            m
+        })
+        .collect()
+}
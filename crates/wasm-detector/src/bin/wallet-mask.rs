@@ -0,0 +1,266 @@
+//! Command-line front end for scanning files outside the browser.
+//!
+//! ```text
+//! wallet-mask scan [--baseline FILE] [--write-baseline FILE] <FILE>...
+//! wallet-mask mask [--output FILE] [--audit-log FILE --audit-salt SALT] <FILE>
+//! wallet-mask report [--format text|json] <FILE>...
+//! wallet-mask fixture <FILE>...
+//! wallet-mask generate [--seed N] [--count N]
+//! wallet-mask bench [--iterations N] <FILE>...
+//! ```
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Instant;
+use wasm_detector::audit::{self, AuditRecord, AuditSink};
+use wasm_detector::bytes::collect_matches_bytes_with_options;
+use wasm_detector::fixture::run_fixture;
+use wasm_detector::generate::Generator;
+use wasm_detector::mask::mask_bytes;
+use wasm_detector::{Baseline, DetectorOptions, Match, PATTERNS, mmap};
+
+const USAGE: &str = "usage:
+  wallet-mask scan [--baseline FILE] [--write-baseline FILE] <FILE>...
+  wallet-mask mask [--output FILE] [--audit-log FILE --audit-salt SALT] <FILE>
+  wallet-mask report [--format text|json] <FILE>...
+  wallet-mask fixture <FILE>...
+  wallet-mask generate [--seed N] [--count N]
+  wallet-mask bench [--iterations N] <FILE>...";
+
+type Flags<'a> = Vec<(&'a str, &'a str)>;
+
+/// Splits arguments into `--flag value` pairs and positional operands.
+fn parse_args(args: &[String]) -> Result<(Flags<'_>, Vec<&str>), String> {
+    let mut flags = Vec::new();
+    let mut operands = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if let Some(flag) = arg.strip_prefix("--") {
+            let value = iter
+                .next()
+                .ok_or_else(|| format!("--{flag} requires a value"))?;
+            flags.push((flag, value.as_str()));
+        } else {
+            operands.push(arg.as_str());
+        }
+    }
+    Ok((flags, operands))
+}
+
+fn flag<'a>(flags: &[(&str, &'a str)], name: &str) -> Option<&'a str> {
+    flags.iter().find(|(f, _)| *f == name).map(|(_, v)| *v)
+}
+
+fn numeric_flag(flags: &[(&str, &str)], name: &str, default: u64) -> Result<u64, String> {
+    flag(flags, name).map_or(Ok(default), |v| {
+        v.parse().map_err(|_| format!("--{name} expects a number"))
+    })
+}
+
+fn scan(flags: &[(&str, &str)], files: &[&str]) -> Result<ExitCode, String> {
+    let options = DetectorOptions::default();
+    let baseline = match flag(flags, "baseline") {
+        Some(path) => {
+            let json = std::fs::read_to_string(path).map_err(|e| format!("{path}: {e}"))?;
+            Baseline::from_json(&json)?
+        }
+        None => Baseline::default(),
+    };
+
+    let mut all: Vec<Match> = Vec::new();
+    let mut reported = 0;
+    for file in files {
+        let matches =
+            mmap::scan_file(Path::new(file), &options).map_err(|e| format!("{file}: {e}"))?;
+        let new = baseline.suppress(matches.clone());
+        for m in &new {
+            println!(
+                "{file}:{}: {} ({}) {}",
+                m.index, m.type_, m.rule_id, m.value
+            );
+        }
+        reported += new.len();
+        // The written baseline covers every current finding, accepted or
+        // new, so regenerating it from its own output changes nothing.
+        all.extend(matches);
+    }
+
+    if let Some(path) = flag(flags, "write-baseline") {
+        let out = PathBuf::from(path);
+        std::fs::write(&out, Baseline::from_matches(&all).to_json())
+            .map_err(|e| format!("{path}: {e}"))?;
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    Ok(if reported == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+/// Appends audit records to a file as JSON lines.
+struct AuditFile(std::sync::Mutex<std::fs::File>);
+
+impl AuditSink for AuditFile {
+    fn record(&self, record: AuditRecord) {
+        let line = serde_json::to_string(&record).unwrap();
+        if let Err(e) = writeln!(self.0.lock().unwrap(), "{line}") {
+            eprintln!("audit log: {e}");
+        }
+    }
+}
+
+/// Writes a scrubbed copy of the file where every match keeps its exact
+/// length and character class, to `--output` or stdout.
+fn mask(flags: &[(&str, &str)], file: &str) -> Result<ExitCode, String> {
+    if let Some(path) = flag(flags, "audit-log") {
+        let salt = flag(flags, "audit-salt").ok_or("--audit-log requires --audit-salt")?;
+        let log = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|e| format!("{path}: {e}"))?;
+        audit::set_audit_sink(salt.as_bytes(), Box::new(AuditFile(log.into())));
+    }
+
+    let matches = mmap::scan_file(Path::new(file), &DetectorOptions::default())
+        .map_err(|e| format!("{file}: {e}"))?;
+    let mut contents = std::fs::read(file).map_err(|e| format!("{file}: {e}"))?;
+    mask_bytes(&mut contents, &matches);
+
+    match flag(flags, "output") {
+        Some(path) => std::fs::write(path, &contents).map_err(|e| format!("{path}: {e}"))?,
+        None => std::io::stdout()
+            .write_all(&contents)
+            .map_err(|e| e.to_string())?,
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Prints a redaction report covering every file, without the matched values.
+fn report(flags: &[(&str, &str)], files: &[&str]) -> Result<ExitCode, String> {
+    let mut all: Vec<Match> = Vec::new();
+    for file in files {
+        let matches = mmap::scan_file(Path::new(file), &DetectorOptions::default())
+            .map_err(|e| format!("{file}: {e}"))?;
+        all.extend(matches);
+    }
+    let report = wasm_detector::report::report(&all);
+    match flag(flags, "format").unwrap_or("text") {
+        "text" => print!("{}", report.to_text()),
+        "json" => println!("{}", report.to_json()),
+        other => return Err(format!("unknown report format: {other}")),
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Runs shared fixture files; exits 1 if any fails.
+fn fixture(files: &[&str]) -> Result<ExitCode, String> {
+    let mut failed = 0;
+    for file in files {
+        let json = std::fs::read_to_string(file).map_err(|e| format!("{file}: {e}"))?;
+        let outcome = run_fixture(&json).map_err(|e| format!("{file}: {e}"))?;
+        if outcome.passed {
+            println!("ok   {file} {}", outcome.name);
+            continue;
+        }
+        failed += 1;
+        println!("FAIL {file} {}", outcome.name);
+        for m in &outcome.missing {
+            println!("  missing    {}: {} {}", m.index, m.type_, m.value);
+        }
+        for m in &outcome.unexpected {
+            println!("  unexpected {}: {} {}", m.index, m.type_, m.value);
+        }
+    }
+    Ok(if failed == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+fn generate(flags: &[(&str, &str)]) -> Result<ExitCode, String> {
+    let seed = numeric_flag(flags, "seed", 0)?;
+    let count = numeric_flag(flags, "count", 20)?;
+    let document = Generator::new(seed).document(count as usize);
+    print!("{}", document.text);
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Times `iterations` scans of every sample with the given options.
+fn time_scans(samples: &[Vec<u8>], options: &DetectorOptions, iterations: u64) -> (usize, f64) {
+    let mut matches = 0;
+    let started = Instant::now();
+    for _ in 0..iterations {
+        matches = samples
+            .iter()
+            .map(|s| {
+                collect_matches_bytes_with_options(s, options)
+                    .expect("bench options set no size limits")
+                    .len()
+            })
+            .sum();
+    }
+    (matches, started.elapsed().as_secs_f64())
+}
+
+fn bench(flags: &[(&str, &str)], files: &[&str]) -> Result<ExitCode, String> {
+    let iterations = numeric_flag(flags, "iterations", 5)?.max(1);
+    let samples = files
+        .iter()
+        .map(|f| std::fs::read(f).map_err(|e| format!("{f}: {e}")))
+        .collect::<Result<Vec<_>, _>>()?;
+    let total_bytes: usize = samples.iter().map(Vec::len).sum();
+    let megabytes = (total_bytes as u64 * iterations) as f64 / 1_000_000.0;
+
+    println!("{total_bytes} bytes x {iterations} iterations");
+    println!("{:<28} {:>10} {:>12}", "family", "matches", "MB/s");
+
+    for pattern in PATTERNS {
+        // Scan with every other pattern disabled to isolate this one's cost.
+        let options = DetectorOptions {
+            disabled: PATTERNS
+                .iter()
+                .filter(|p| p.rule_id != pattern.rule_id)
+                .map(|p| p.type_.to_string())
+                .collect(),
+            ..DetectorOptions::default()
+        };
+        let (matches, secs) = time_scans(&samples, &options, iterations);
+        println!(
+            "{:<28} {matches:>10} {:>12.1}",
+            pattern.rule_id,
+            megabytes / secs
+        );
+    }
+
+    let (matches, secs) = time_scans(&samples, &DetectorOptions::default(), iterations);
+    println!("{:<28} {matches:>10} {:>12.1}", "all", megabytes / secs);
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn run(args: &[String]) -> Result<ExitCode, String> {
+    let (command, rest) = args.split_first().ok_or(USAGE)?;
+    let (flags, operands) = parse_args(rest)?;
+    match command.as_str() {
+        "scan" if !operands.is_empty() => scan(&flags, &operands),
+        "mask" if operands.len() == 1 => mask(&flags, operands[0]),
+        "report" if !operands.is_empty() => report(&flags, &operands),
+        "fixture" if !operands.is_empty() => fixture(&operands),
+        "generate" => generate(&flags),
+        "bench" if !operands.is_empty() => bench(&flags, &operands),
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    run(&args).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        ExitCode::from(2)
+    })
+}
@@ -0,0 +1,20 @@
+//! WASI entry point: reads text from stdin and writes the matches to stdout as JSON.
+//!
+//! Build with `cargo build --release --target wasm32-wasip1 --no-default-features --features wasi,families`;
+//! without `families` (or a list of family features) the binary has no patterns.
+
+use std::io::{self, Read, Write};
+
+fn main() {
+    let mut text = String::new();
+    if let Err(e) = io::stdin().read_to_string(&mut text) {
+        eprintln!("Failed to read stdin: {e}");
+        std::process::exit(1);
+    }
+
+    let matches = wasm_detector::collect_matches(&text);
+
+    let mut stdout = io::stdout().lock();
+    serde_json::to_writer(&mut stdout, &matches).unwrap();
+    writeln!(stdout).unwrap();
+}
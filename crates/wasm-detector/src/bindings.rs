@@ -0,0 +1,462 @@
+use crate::address_book::find_lookalikes;
+use crate::annotate::annotate as annotate_text;
+use crate::batch::{NodeText, collect_matches_batch};
+#[cfg(feature = "bech32")]
+use crate::bech32;
+use crate::confusion::find_confusions;
+use crate::evaluate::{LabeledSample, evaluate as evaluate_corpus};
+use crate::explain::explain as explain_matches;
+use crate::format::EllipsisStyle;
+use crate::generate::Generator;
+use crate::profiles;
+use crate::{
+    Baseline, DetectorError, DetectorOptions, collect_matches, collect_matches_capped,
+    collect_matches_with_options,
+};
+use wasm_bindgen::prelude::*;
+
+/// Errors reach JS as structured objects, e.g. `{ kind: "pattern_too_large", rule_id, message }`.
+impl From<DetectorError> for JsValue {
+    fn from(e: DetectorError) -> Self {
+        serde_wasm_bindgen::to_value(&e).unwrap()
+    }
+}
+
+// --- Exported API ---
+
+#[wasm_bindgen]
+pub fn find_matches(text: &str) -> JsValue {
+    serde_wasm_bindgen::to_value(&collect_matches(text)).unwrap()
+}
+
+/// Serializes maps as plain objects rather than JS `Map`s, for JSON-shaped results.
+fn to_json_value<T: serde::Serialize>(value: &T) -> JsValue {
+    let serializer = serde_wasm_bindgen::Serializer::json_compatible();
+    value.serialize(&serializer).unwrap()
+}
+
+/// The options used when none are passed, with every default filled in.
+#[wasm_bindgen]
+pub fn default_options() -> JsValue {
+    to_json_value(&crate::default_options())
+}
+
+/// JSON Schema for the options object accepted by this build, for
+/// generating and validating settings UIs.
+#[wasm_bindgen]
+pub fn options_schema() -> JsValue {
+    to_json_value(&crate::options_schema())
+}
+
+/// Accepts an optional options object; `undefined`/`null` selects the defaults.
+fn parse_options(options: JsValue) -> Result<DetectorOptions, JsValue> {
+    if options.is_undefined() || options.is_null() {
+        return Ok(DetectorOptions::default());
+    }
+    Ok(serde_wasm_bindgen::from_value(options)?)
+}
+
+/// Like `find_matches`, with per-call options such as `{ promote: ["sol"] }`
+/// or `{ regex_size_limit: 65536 }`. Throws a structured error if a pattern
+/// does not fit within the requested limits.
+#[wasm_bindgen]
+pub fn find_matches_with_options(text: &str, options: JsValue) -> Result<JsValue, JsValue> {
+    let options = parse_options(options)?;
+    let matches = collect_matches_with_options(text, &options)?;
+    Ok(serde_wasm_bindgen::to_value(&matches).unwrap())
+}
+
+/// Like `find_matches_with_options`, returning `{ matches, dropped? }` where
+/// `dropped` counts, per type, the matches `max_matches` cut.
+#[wasm_bindgen]
+pub fn find_matches_capped(text: &str, options: JsValue) -> Result<JsValue, JsValue> {
+    let options = parse_options(options)?;
+    let capped = collect_matches_capped(text, &options)?;
+    Ok(to_json_value(&capped))
+}
+
+/// Splits text into `{ kind: "text" | "match", text, match? }` segments,
+/// HTML-escaping each segment's `text` when `escape_html` is set.
+#[wasm_bindgen]
+pub fn annotate(text: &str, escape_html: bool) -> JsValue {
+    serde_wasm_bindgen::to_value(&annotate_text(text, escape_html)).unwrap()
+}
+
+/// Dry run: every candidate, including rejected ones with the reason they were dropped.
+#[wasm_bindgen]
+pub fn explain(text: &str, options: JsValue) -> Result<JsValue, JsValue> {
+    let options = parse_options(options)?;
+    Ok(serde_wasm_bindgen::to_value(&explain_matches(text, &options)?).unwrap())
+}
+
+/// Scans `texts` and returns only per-type counts, with types seen fewer
+/// than `k` times suppressed; matched values never leave wasm. `k`
+/// defaults to 5.
+#[wasm_bindgen]
+pub fn aggregate_texts(
+    texts: Vec<String>,
+    options: JsValue,
+    k: Option<usize>,
+) -> Result<JsValue, JsValue> {
+    let options = parse_options(options)?;
+    let k = k.unwrap_or(crate::aggregate::DEFAULT_K);
+    Ok(to_json_value(&crate::aggregate::aggregate_texts(
+        &texts, &options, k,
+    )?))
+}
+
+/// Redaction report for `text`: per-rule counts, severities and policy
+/// actions plus the detector version, without the matched values.
+#[wasm_bindgen]
+pub fn redaction_report(text: &str, options: JsValue) -> Result<JsValue, JsValue> {
+    let options = parse_options(options)?;
+    let matches = collect_matches_with_options(text, &options)?;
+    Ok(to_json_value(&crate::report::report(&matches)))
+}
+
+/// Like `redaction_report`, rendered as plain text.
+#[wasm_bindgen]
+pub fn redaction_report_text(text: &str, options: JsValue) -> Result<String, JsValue> {
+    let options = parse_options(options)?;
+    let matches = collect_matches_with_options(text, &options)?;
+    Ok(crate::report::report(&matches).to_text())
+}
+
+/// Scans `text` and returns it with every match masked in place.
+#[wasm_bindgen]
+pub fn mask_text(text: &str, options: JsValue) -> Result<String, JsValue> {
+    let options = parse_options(options)?;
+    Ok(crate::mask::mask_text(text, &options)?)
+}
+
+/// Rewrites every match in the style a policy such as
+/// `{ default: { style: "placeholder" }, types: { eth_tx_hash: { style: "partial", head: 4, tail: 4 } } }`
+/// gives its type.
+#[wasm_bindgen]
+pub fn apply_policy(text: &str, options: JsValue, policy: JsValue) -> Result<String, JsValue> {
+    let options = parse_options(options)?;
+    let policy: crate::mask::MaskPolicy = if policy.is_undefined() || policy.is_null() {
+        Default::default()
+    } else {
+        serde_wasm_bindgen::from_value(policy)?
+    };
+    Ok(crate::mask::apply_policy(text, &options, &policy)?)
+}
+
+/// Re-scans masked output and returns any remaining matches (leaks),
+/// ignoring masks themselves and `allowlist` values. Empty means clean.
+#[wasm_bindgen]
+pub fn verify_masked(
+    masked: &str,
+    options: JsValue,
+    allowlist: Vec<String>,
+) -> Result<JsValue, JsValue> {
+    let options = parse_options(options)?;
+    let leaks = crate::mask::verify_masked(masked, &options, &allowlist)?;
+    Ok(serde_wasm_bindgen::to_value(&leaks).unwrap())
+}
+
+/// Runs a shared JSON fixture (input, options, expected matches) and
+/// reports what was missing or unexpected.
+#[wasm_bindgen]
+pub fn run_fixture(json: &str) -> Result<JsValue, JsValue> {
+    let outcome = crate::fixture::run_fixture(json).map_err(|e| JsValue::from_str(&e))?;
+    Ok(to_json_value(&outcome))
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Any JS function; invoked through `Function.prototype.call`.
+    pub type MatchCallback;
+
+    #[wasm_bindgen(method, catch, js_name = call)]
+    fn call(this: &MatchCallback, this_arg: &JsValue, m: JsValue) -> Result<JsValue, JsValue>;
+}
+
+/// Invokes `callback(match)` for each match as its part of the text is
+/// scanned, top first, instead of returning one buffered array. Returning
+/// `false` from the callback stops the scan; a throw is re-thrown.
+#[wasm_bindgen]
+pub fn find_matches_cb(
+    text: &str,
+    callback: &MatchCallback,
+    options: JsValue,
+) -> Result<(), JsValue> {
+    let options = parse_options(options)?;
+    let mut thrown = None;
+    crate::stream::for_each_match(text, &options, |m| {
+        let m = serde_wasm_bindgen::to_value(&m).unwrap();
+        match callback.call(&JsValue::NULL, m) {
+            Ok(result) => result.as_bool() != Some(false),
+            Err(e) => {
+                thrown = Some(e);
+                false
+            }
+        }
+    })?;
+    thrown.map_or(Ok(()), Err)
+}
+
+/// HTML-aware scan: text content plus `href`, `title`, `data-*` and input
+/// `value` attributes, with `metadata.attribute` naming the source attribute.
+#[wasm_bindgen]
+pub fn find_matches_html(html: &str, options: JsValue) -> Result<JsValue, JsValue> {
+    let options = parse_options(options)?;
+    let matches = crate::html::collect_matches_html(html, &options)?;
+    Ok(serde_wasm_bindgen::to_value(&matches).unwrap())
+}
+
+/// Scans with the site profile registered for `hostname`, or the defaults if none applies.
+#[wasm_bindgen]
+pub fn find_matches_for_host(text: &str, hostname: &str) -> Result<JsValue, JsValue> {
+    let options = profiles::profile_for_host(hostname).unwrap_or_default();
+    let matches = collect_matches_with_options(text, &options)?;
+    Ok(serde_wasm_bindgen::to_value(&matches).unwrap())
+}
+
+/// Scans a serialized subtree, `[{ nodeId, text }, ...]`, in one call and
+/// returns `{ [nodeId]: matches }` for the nodes that have matches.
+#[wasm_bindgen]
+pub fn find_matches_batch(nodes: JsValue, options: JsValue) -> Result<JsValue, JsValue> {
+    let nodes: Vec<NodeText> = serde_wasm_bindgen::from_value(nodes)?;
+    let options = parse_options(options)?;
+    Ok(to_json_value(&collect_matches_batch(&nodes, &options)?))
+}
+
+/// `find_matches_batch` with the site profile registered for `hostname`.
+#[wasm_bindgen]
+pub fn find_matches_batch_for_host(nodes: JsValue, hostname: &str) -> Result<JsValue, JsValue> {
+    let nodes: Vec<NodeText> = serde_wasm_bindgen::from_value(nodes)?;
+    let options = profiles::profile_for_host(hostname).unwrap_or_default();
+    Ok(to_json_value(&collect_matches_batch(&nodes, &options)?))
+}
+
+/// Registers or overrides the profile used for hosts matching `pattern` (e.g. `etherscan.*`).
+#[wasm_bindgen]
+pub fn register_profile(pattern: &str, options: JsValue) -> Result<(), JsValue> {
+    profiles::register_profile(pattern, parse_options(options)?);
+    Ok(())
+}
+
+/// Serializes every match in `text` as a baseline file of accepted findings.
+#[wasm_bindgen]
+pub fn write_baseline(text: &str) -> String {
+    Baseline::from_matches(&collect_matches(text)).to_json()
+}
+
+/// Like `find_matches`, but drops matches already recorded in the given baseline.
+#[wasm_bindgen]
+pub fn find_matches_with_baseline(text: &str, baseline: &str) -> Result<JsValue, JsValue> {
+    let baseline = Baseline::from_json(baseline).map_err(|e| JsValue::from_str(&e))?;
+    let matches = baseline.suppress(collect_matches(text));
+    Ok(serde_wasm_bindgen::to_value(&matches).unwrap())
+}
+
+/// Scores the detectors against a labeled corpus of `{ text, expected }` samples.
+#[wasm_bindgen]
+pub fn evaluate(corpus: JsValue) -> Result<JsValue, JsValue> {
+    let corpus: Vec<LabeledSample> = serde_wasm_bindgen::from_value(corpus)?;
+    Ok(serde_wasm_bindgen::to_value(&evaluate_corpus(&corpus)).unwrap())
+}
+
+/// Produces a reproducible synthetic document with `count` embedded chain values.
+#[wasm_bindgen]
+pub fn generate_document(seed: u32, count: usize) -> JsValue {
+    let document = Generator::new(seed as u64).document(count);
+    serde_wasm_bindgen::to_value(&document).unwrap()
+}
+
+/// Lists matches that another pattern family also matched, with the discarded alternatives.
+#[wasm_bindgen]
+pub fn confusion_report(text: &str) -> JsValue {
+    serde_wasm_bindgen::to_value(&find_confusions(text)).unwrap()
+}
+
+/// Flags addresses within `max_distance` edits of, but not equal to, an entry
+/// in the user's address book — a likely address-poisoning attempt.
+#[wasm_bindgen]
+pub fn check_address_book(text: &str, book: Vec<String>, max_distance: usize) -> JsValue {
+    serde_wasm_bindgen::to_value(&find_lookalikes(text, &book, max_distance)).unwrap()
+}
+
+/// Maps a bech32 human-readable part to a chain, e.g. `register_hrp("osmo", "osmosis")`.
+#[cfg(feature = "bech32")]
+#[wasm_bindgen]
+pub fn register_hrp(hrp: &str, chain: &str) {
+    bech32::register_hrp(hrp, chain);
+}
+
+/// Resolves a bech32 address to the chain registered for its HRP.
+#[cfg(feature = "bech32")]
+#[wasm_bindgen]
+pub fn resolve_hrp(address: &str) -> Option<String> {
+    bech32::resolve_chain(address)
+}
+
+/// Names the EVM network for a chain id, e.g. `resolve_chain_id(137)` → `"Polygon"`.
+#[cfg(feature = "evm")]
+#[wasm_bindgen]
+pub fn resolve_chain_id(id: u64) -> Option<String> {
+    crate::chain_registry::resolve_chain_id(id)
+}
+
+/// Registry entry `{ id, name, native_symbol, explorer }` for a chain id, or `undefined`.
+#[cfg(feature = "evm")]
+#[wasm_bindgen]
+pub fn chain_info(id: u64) -> JsValue {
+    serde_wasm_bindgen::to_value(&crate::chain_registry::chain_info(id)).unwrap()
+}
+
+/// Merges fresh chain data (same shape as `chain_info` entries) into the
+/// registry and returns the number of entries loaded.
+#[cfg(feature = "evm")]
+#[wasm_bindgen]
+pub fn load_chain_registry(json: &str) -> Result<usize, JsValue> {
+    crate::chain_registry::load_chain_registry(json).map_err(|e| JsValue::from_str(&e))
+}
+
+/// Pins an ed25519 public key (hex) allowed to sign rules bundles.
+#[wasm_bindgen]
+pub fn trust_rules_key(public_key_hex: &str) -> Result<(), JsValue> {
+    crate::rules::trust_rules_key(public_key_hex).map_err(|e| JsValue::from_str(&e))
+}
+
+/// Applies a rules bundle (extra patterns, value labels, priority hints) if
+/// its hex ed25519 signature verifies against a pinned key and it is valid
+/// and newer than the loaded one; returns the active rule count.
+#[wasm_bindgen]
+pub fn load_rules(json: &str, signature_hex: &str) -> Result<usize, JsValue> {
+    crate::rules::load_rules(json, signature_hex).map_err(|e| JsValue::from_str(&e))
+}
+
+/// A scan of one huge string that advances a chunk per `step()`, so a page
+/// can `await` between steps and show `progress()`:
+/// `while (scan.step()) { bar.value = scan.progress(); await tick(); }`.
+#[wasm_bindgen]
+pub struct ChunkedScanner(crate::chunked::ChunkedScan);
+
+#[wasm_bindgen]
+impl ChunkedScanner {
+    /// `chunk_bytes` defaults to 64 KiB.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        text: String,
+        options: JsValue,
+        chunk_bytes: Option<usize>,
+    ) -> Result<ChunkedScanner, JsValue> {
+        let options = parse_options(options)?;
+        let chunk_bytes = chunk_bytes.unwrap_or(crate::chunked::DEFAULT_CHUNK_BYTES);
+        Ok(ChunkedScanner(crate::chunked::ChunkedScan::new(
+            text,
+            options,
+            chunk_bytes,
+        )?))
+    }
+
+    /// Scans the next chunk; `false` once the whole text is done.
+    pub fn step(&mut self) -> bool {
+        self.0.step()
+    }
+
+    /// Fraction of the text scanned so far, from 0 to 1.
+    pub fn progress(&self) -> f64 {
+        let (done, total) = self.0.progress();
+        if total == 0 {
+            1.0
+        } else {
+            done as f64 / total as f64
+        }
+    }
+
+    /// `{ matches, dropped }`, as `find_matches_capped` returns. Consumes
+    /// the scanner.
+    pub fn finish(self) -> JsValue {
+        to_json_value(&self.0.finish())
+    }
+}
+
+/// Version of the loaded rules bundle, or 0 when only built-in rules apply.
+#[wasm_bindgen]
+pub fn rules_version() -> u64 {
+    crate::rules::rules_version()
+}
+
+/// Drops the loaded rules bundle.
+#[wasm_bindgen]
+pub fn clear_rules() {
+    crate::rules::clear_rules();
+}
+
+/// Buffers audit records in memory until `take_audit_log` collects them.
+#[cfg(feature = "audit")]
+struct BufferedAudit(std::sync::Mutex<Vec<crate::audit::AuditRecord>>);
+
+#[cfg(feature = "audit")]
+impl crate::audit::AuditSink for BufferedAudit {
+    fn record(&self, record: crate::audit::AuditRecord) {
+        self.0.lock().unwrap().push(record);
+    }
+}
+
+#[cfg(feature = "audit")]
+static AUDIT_BUFFER: std::sync::OnceLock<std::sync::Arc<BufferedAudit>> =
+    std::sync::OnceLock::new();
+
+#[cfg(feature = "audit")]
+fn audit_buffer() -> &'static std::sync::Arc<BufferedAudit> {
+    AUDIT_BUFFER.get_or_init(|| std::sync::Arc::new(BufferedAudit(Default::default())))
+}
+
+/// Starts recording `{ timestamp_ms, type_, fingerprint, action }` entries,
+/// fingerprinting values with `salt`.
+#[cfg(feature = "audit")]
+#[wasm_bindgen]
+pub fn enable_audit(salt: &str) {
+    crate::audit::set_audit_sink(salt.as_bytes(), Box::new(audit_buffer().clone()));
+}
+
+/// Records that the page masked a value; `timestamp_ms` is typically `Date.now()`.
+#[cfg(feature = "audit")]
+#[wasm_bindgen]
+pub fn audit_masked(type_: &str, value: &str, timestamp_ms: f64) {
+    crate::audit::record_with_time(type_, value, "masked", timestamp_ms as u64);
+}
+
+/// Returns and clears the audit records gathered since the last call.
+#[cfg(feature = "audit")]
+#[wasm_bindgen]
+pub fn take_audit_log() -> JsValue {
+    let records = std::mem::take(&mut *audit_buffer().0.lock().unwrap());
+    serde_wasm_bindgen::to_value(&records).unwrap()
+}
+
+/// Explorer-style short form of a value (`0x5aAeb6...1BeAed`); `style` is
+/// `"ascii"` (`...`) or `"unicode"` (`…`). The result stays recognizable as truncated.
+#[wasm_bindgen]
+pub fn format_short(value: &str, head: usize, tail: usize, style: &str) -> Result<String, JsValue> {
+    let style = EllipsisStyle::from_name(style)
+        .ok_or_else(|| JsValue::from_str(&format!("Unknown ellipsis style: {style}")))?;
+    Ok(crate::format::format_short(value, head, tail, style))
+}
+
+/// The context hash used for `anchor_before`/`anchor_after`, so hosts can
+/// hash the text around a remembered position and compare.
+#[wasm_bindgen]
+pub fn anchor_hash(context: &str) -> String {
+    crate::anchor::anchor_hash(context)
+}
+
+/// Lists the cargo features compiled into this wasm build (pattern families included).
+#[wasm_bindgen]
+pub fn enabled_features() -> Vec<String> {
+    crate::enabled_features()
+        .into_iter()
+        .map(String::from)
+        .collect()
+}
+
+/// Cold-start friendly initializer for isolates: warms the common EVM patterns only.
+#[wasm_bindgen(js_name = initLite)]
+pub fn init_lite() {
+    crate::precompile_evm_patterns();
+}
@@ -0,0 +1,49 @@
+use crate::{
+    DetectorError, DetectorOptions, Match, collect_with, group_and_cap, is_editable,
+    limited_regexes,
+};
+use regex::Regex;
+
+/// Runs the same pipeline as `collect_matches_with_options` over one window
+/// of bytes, before `group_positions` and `max_matches`. Each run of valid
+/// UTF-8 is scanned in place as a `&str`; invalid bytes only separate runs,
+/// so nothing is copied and offsets stay byte offsets into `haystack`.
+pub(crate) fn scan_bytes(
+    haystack: &[u8],
+    options: &DetectorOptions,
+    limited: Option<&[Regex]>,
+) -> Vec<Match> {
+    let mut matches = Vec::new();
+    let mut start = 0;
+    for chunk in haystack.utf8_chunks() {
+        let run = chunk.valid();
+        if !run.is_empty() {
+            for mut m in collect_with(run, options, limited) {
+                m.shift(start);
+                m.editable = is_editable(&m, &options.editable_ranges);
+                matches.push(m);
+            }
+        }
+        start += run.len() + chunk.invalid().len();
+    }
+    matches
+}
+
+/// Scans raw bytes (e.g. a huge log file) that may contain invalid UTF-8.
+/// The bytes are validated but never copied; indices are byte offsets into
+/// `haystack`.
+pub fn collect_matches_bytes(haystack: &[u8]) -> Vec<Match> {
+    collect_matches_bytes_with_options(haystack, &DetectorOptions::default())
+        .expect("default options set no size limits")
+}
+
+/// Like `collect_matches_bytes`, with every option applied as
+/// `collect_matches_with_options` applies it.
+pub fn collect_matches_bytes_with_options(
+    haystack: &[u8],
+    options: &DetectorOptions,
+) -> Result<Vec<Match>, DetectorError> {
+    let limited = limited_regexes(options)?;
+    let matches = scan_bytes(haystack, options, limited.as_deref());
+    Ok(group_and_cap(matches, options).matches)
+}
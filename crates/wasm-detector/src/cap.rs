@@ -0,0 +1,40 @@
+//! Result-size cap. When a scan finds more matches than the host's
+//! `max_matches`, the ones that matter most are kept rather than the first
+//! ones by position: complete values before low-confidence ones (glued to
+//! neighbouring text or OCR-corrected), then truncated values, then context
+//! such as chain ids. What was dropped is reported per type.
+
+use crate::Match;
+use crate::report::Severity;
+use serde::Serialize;
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+
+#[derive(Serialize, Clone)]
+pub struct Capped {
+    /// The retained matches, in text order.
+    pub matches: Vec<Match>,
+    /// Occurrences dropped by the cap, per type.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub dropped: BTreeMap<String, usize>,
+}
+
+/// Lower sorts first: highest severity, then confident before low-confidence.
+fn retention_rank(m: &Match) -> (Reverse<Severity>, bool) {
+    let low_confidence = m.corrected || m.metadata.contains_key("confidence");
+    (Reverse(Severity::for_rule(&m.rule_id)), low_confidence)
+}
+
+/// Keeps at most `max` matches by retention rank, ties going to the earlier
+/// match, and counts the rest in `dropped`.
+pub fn cap_matches(mut matches: Vec<Match>, max: usize) -> Capped {
+    let mut dropped = BTreeMap::new();
+    if matches.len() > max {
+        matches.sort_by_key(retention_rank);
+        for m in matches.split_off(max) {
+            *dropped.entry(m.type_.clone()).or_default() += m.starts().len();
+        }
+        matches.sort_by_key(|m| m.index);
+    }
+    Capped { matches, dropped }
+}
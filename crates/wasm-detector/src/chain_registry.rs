@@ -0,0 +1,142 @@
+//! Registry of well-known EVM chains, used to name the network behind
+//! `chainId: 137`-style references. A compact copy ships with the crate and
+//! hosts can refresh it at runtime with `load_chain_registry`.
+
+use crate::{Match, has_overlap};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::{OnceLock, RwLock};
+
+/// Chains bundled with the crate.
+const BUILTIN_CHAINS: &str = include_str!("chain_registry.json");
+
+/// How far (in bytes) a bare number may sit from an EVM address or hash and
+/// still be read as that value's chain id. Only punctuation and whitespace
+/// may separate the two.
+const BARE_ID_WINDOW: usize = 16;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ChainInfo {
+    pub id: u64,
+    pub name: String,
+    pub native_symbol: String,
+    /// Block explorer base URL.
+    pub explorer: String,
+}
+
+static REGISTRY: OnceLock<RwLock<BTreeMap<u64, ChainInfo>>> = OnceLock::new();
+static BARE_ID_RE: OnceLock<Regex> = OnceLock::new();
+
+fn registry() -> &'static RwLock<BTreeMap<u64, ChainInfo>> {
+    REGISTRY.get_or_init(|| {
+        let chains: Vec<ChainInfo> = serde_json::from_str(BUILTIN_CHAINS).unwrap();
+        RwLock::new(chains.into_iter().map(|c| (c.id, c)).collect())
+    })
+}
+
+fn get_bare_id_re() -> &'static Regex {
+    BARE_ID_RE.get_or_init(|| Regex::new(r"\b[1-9][0-9]{1,7}\b").unwrap())
+}
+
+/// Returns the network name for an EVM chain id, e.g. `137` → `Polygon`.
+pub fn resolve_chain_id(id: u64) -> Option<String> {
+    registry().read().unwrap().get(&id).map(|c| c.name.clone())
+}
+
+/// Full registry entry for a chain id.
+pub fn chain_info(id: u64) -> Option<ChainInfo> {
+    registry().read().unwrap().get(&id).cloned()
+}
+
+/// Merges a JSON array of chain entries into the registry, replacing any
+/// existing entry with the same id, and returns how many were loaded. Lets
+/// hosts ship fresh chain data without a new wasm release.
+pub fn load_chain_registry(json: &str) -> Result<usize, String> {
+    let chains: Vec<ChainInfo> =
+        serde_json::from_str(json).map_err(|e| format!("Invalid chain registry: {e}"))?;
+    let count = chains.len();
+    registry()
+        .write()
+        .unwrap()
+        .extend(chains.into_iter().map(|c| (c.id, c)));
+    Ok(count)
+}
+
+/// Extracts the numeric id from `chainId: 137`, `chain_id="0x89"` or `eip155:42161`.
+fn parse_chain_reference(value: &str) -> Option<u64> {
+    let digits = value.rsplit(|c: char| !c.is_ascii_alphanumeric()).next()?;
+    match digits
+        .strip_prefix("0x")
+        .or_else(|| digits.strip_prefix("0X"))
+    {
+        Some(hex) => u64::from_str_radix(hex, 16).ok(),
+        None => digits.parse().ok(),
+    }
+}
+
+fn attach_network(m: &mut Match, id: u64) {
+    m.metadata.insert("chain_id".to_string(), id.to_string());
+    if let Some(name) = resolve_chain_id(id) {
+        m.metadata.insert("network".to_string(), name);
+    }
+}
+
+/// Names the network of every chain-id match and, unless `chain_id` is
+/// disabled, reports bare well-known ids sitting right next to an EVM
+/// address or hash (`42161:0x…`, `0x… (137)`).
+pub(crate) fn resolve_networks(text: &str, matches: &mut Vec<Match>, bare_ids: bool) {
+    for m in matches.iter_mut().filter(|m| m.type_ == "chain_id") {
+        if let Some(id) = parse_chain_reference(&m.value) {
+            attach_network(m, id);
+        }
+    }
+    if !bare_ids {
+        return;
+    }
+
+    let anchors: Vec<(usize, usize)> = matches
+        .iter()
+        .filter(|m| m.rule_id == "evm.address" || m.rule_id == "evm.tx_hash")
+        .map(|m| (m.index, m.index + m.value.len()))
+        .collect();
+
+    let mut found = Vec::new();
+    for cap in get_bare_id_re().find_iter(text) {
+        let (start, end) = (cap.start(), cap.end());
+        let adjacent =
+            |gap: &str| gap.len() <= BARE_ID_WINDOW && !gap.chars().any(char::is_alphanumeric);
+        let near = anchors.iter().any(|&(a_start, a_end)| {
+            end <= a_start && adjacent(&text[end..a_start])
+                || a_end <= start && adjacent(&text[a_end..start])
+        });
+        let Ok(id) = cap.as_str().parse::<u64>() else {
+            continue;
+        };
+        if !near || resolve_chain_id(id).is_none() || has_overlap(start, end, matches) {
+            continue;
+        }
+
+        let mut m = Match {
+            value: cap.as_str().to_string(),
+            index: start,
+            type_: "chain_id".to_string(),
+            rule_id: "evm.chain_id.bare".to_string(),
+            compare_key: String::new(),
+            metadata: BTreeMap::new(),
+            possible_chains: Vec::new(),
+            corrected: false,
+            corrected_value: None,
+            editable: false,
+            positions: Vec::new(),
+            network: None,
+            normalized: None,
+            chain: None,
+            kind: String::new(),
+            variant: None,
+        };
+        attach_network(&mut m, id);
+        found.push(m);
+    }
+    matches.extend(found);
+}
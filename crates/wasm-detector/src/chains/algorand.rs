@@ -0,0 +1,36 @@
+//! Algorand addresses: 58 base32 characters encoding a 32-byte ed25519 key
+//! followed by the last four bytes of its SHA-512/256 digest.
+
+use crate::Pattern;
+use crate::chains::base32;
+use regex::Regex;
+use sha2::{Digest, Sha512_256};
+use std::sync::OnceLock;
+
+const ALGORAND_SRC: &str = r"\b[A-Z2-7]{58}\b";
+
+static ALGORAND_RE: OnceLock<Regex> = OnceLock::new();
+
+fn get_algorand_re() -> &'static Regex {
+    ALGORAND_RE.get_or_init(|| Regex::new(ALGORAND_SRC).unwrap())
+}
+
+fn is_valid_algorand(value: &str) -> bool {
+    let Some(bytes) = base32::decode(value) else {
+        return false;
+    };
+    if bytes.len() != 36 {
+        return false;
+    }
+    let (key, checksum) = bytes.split_at(32);
+    Sha512_256::digest(key)[28..] == *checksum
+}
+
+pub(crate) const ADDRESS: Pattern = Pattern {
+    type_: "algo_address",
+    rule_id: "algorand.address",
+    chain: "algorand",
+    source: ALGORAND_SRC,
+    regex: get_algorand_re,
+    validate: Some(is_valid_algorand),
+};
@@ -0,0 +1,48 @@
+//! Avalanche X-Chain and P-Chain addresses: a chain alias, `-`, and a
+//! bech32 address under the `avax` (mainnet) or `fuji` (testnet) HRP, e.g.
+//! `X-avax1…`. C-Chain addresses are EVM hex and need no pattern of their own.
+
+use crate::bech32;
+use crate::{Match, Pattern};
+use regex::Regex;
+use std::sync::OnceLock;
+
+const ADDRESS_SRC: &str = r"\b[XP]-(?:avax|fuji)1[qpzry9x8gf2tvdw0s3jn54khce6mua7l]{38}\b";
+
+static ADDRESS_RE: OnceLock<Regex> = OnceLock::new();
+
+fn get_address_re() -> &'static Regex {
+    ADDRESS_RE.get_or_init(|| Regex::new(ADDRESS_SRC).unwrap())
+}
+
+/// The bech32 part must verify and hold a 20-byte (32-word) address.
+fn is_valid_address(value: &str) -> bool {
+    bech32::decode(&value[2..]).is_some_and(|d| {
+        d.variant == bech32::Variant::Bech32
+            && matches!(d.hrp.as_str(), "avax" | "fuji")
+            && d.data.len() == 32
+    })
+}
+
+/// Records the chain alias (`X` or `P`) and the network of each address.
+pub(crate) fn describe_aliases(matches: &mut [Match]) {
+    for m in matches.iter_mut().filter(|m| m.rule_id == ADDRESS.rule_id) {
+        let network = if m.value[2..].starts_with("avax") {
+            "mainnet"
+        } else {
+            "testnet"
+        };
+        m.metadata
+            .insert("chain_alias".to_string(), m.value[..1].to_string());
+        m.network = Some(network.to_string());
+    }
+}
+
+pub(crate) const ADDRESS: Pattern = Pattern {
+    type_: "avax_address",
+    rule_id: "avalanche.address",
+    chain: "avalanche",
+    source: ADDRESS_SRC,
+    regex: get_address_re,
+    validate: Some(is_valid_address),
+};
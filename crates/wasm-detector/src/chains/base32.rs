@@ -0,0 +1,22 @@
+//! RFC 4648 base32 decoding (uppercase, unpadded), shared by the families
+//! that encode keys with it.
+
+const ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// Decodes `value`, returning `None` on characters outside the alphabet or
+/// non-zero trailing bits.
+pub(crate) fn decode(value: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(value.len() * 5 / 8);
+    let (mut acc, mut bits) = (0u32, 0u32);
+    for c in value.bytes() {
+        let v = ALPHABET.iter().position(|&a| a == c)? as u32;
+        acc = (acc << 5) | v;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((acc >> bits) as u8);
+            acc &= (1 << bits) - 1;
+        }
+    }
+    (acc == 0).then_some(bytes)
+}
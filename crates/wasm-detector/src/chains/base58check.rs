@@ -0,0 +1,32 @@
+//! Base58Check decoding shared by the families that use it: Base58 payload
+//! followed by the first four bytes of its double SHA-256.
+
+use bs58::Alphabet;
+use sha2::{Digest, Sha256};
+
+/// Decodes `value` and verifies its checksum, returning the payload
+/// (version bytes included, checksum removed).
+#[cfg(any(
+    feature = "bitcoin",
+    feature = "tron",
+    feature = "litecoin",
+    feature = "dogecoin",
+    feature = "zcash"
+))]
+pub(crate) fn decode(value: &str) -> Option<Vec<u8>> {
+    decode_with(value, Alphabet::BITCOIN)
+}
+
+/// Like `decode`, for families that use a different Base58 alphabet (XRP).
+pub(crate) fn decode_with(value: &str, alphabet: &Alphabet) -> Option<Vec<u8>> {
+    let mut bytes = bs58::decode(value)
+        .with_alphabet(alphabet)
+        .into_vec()
+        .ok()?;
+    if bytes.len() < 5 {
+        return None;
+    }
+    let checksum = bytes.split_off(bytes.len() - 4);
+    let hash = Sha256::digest(Sha256::digest(&bytes));
+    (hash[..4] == checksum[..]).then_some(bytes)
+}
@@ -0,0 +1,304 @@
+//! Bech32/bech32m codec, the HRP registry, and the generic bech32 address
+//! and truncated patterns.
+
+use crate::{Match, Pattern};
+use regex::Regex;
+use std::collections::BTreeMap;
+use std::sync::{OnceLock, RwLock};
+
+const CHARSET: &[u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const GENERATOR: [u32; 5] = [
+    0x3b6a_57b2,
+    0x2650_8e6d,
+    0x1ea1_19fa,
+    0x3d42_33dd,
+    0x2a14_62b3,
+];
+const BECH32_CONST: u32 = 1;
+const BECH32M_CONST: u32 = 0x2bc8_30a3;
+
+/// Checksum flavor: BIP-173 bech32 or BIP-350 bech32m.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Variant {
+    Bech32,
+    Bech32m,
+}
+
+pub struct Decoded {
+    pub hrp: String,
+    /// 5-bit data words, checksum removed.
+    pub data: Vec<u8>,
+    pub variant: Variant,
+}
+
+fn polymod(values: impl Iterator<Item = u8>) -> u32 {
+    let mut chk: u32 = 1;
+    for v in values {
+        let top = chk >> 25;
+        chk = ((chk & 0x01ff_ffff) << 5) ^ v as u32;
+        for (i, g) in GENERATOR.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                chk ^= g;
+            }
+        }
+    }
+    chk
+}
+
+fn hrp_expand(hrp: &[u8]) -> impl Iterator<Item = u8> + '_ {
+    hrp.iter()
+        .map(|c| c >> 5)
+        .chain(std::iter::once(0))
+        .chain(hrp.iter().map(|c| c & 31))
+}
+
+/// Decodes a bech32/bech32m string and verifies its checksum. Mixed case is
+/// rejected as the spec requires; length is not capped at 90 because some
+/// chains (Cardano) deliberately exceed it.
+pub fn decode(s: &str) -> Option<Decoded> {
+    let has_lower = s.bytes().any(|b| b.is_ascii_lowercase());
+    let has_upper = s.bytes().any(|b| b.is_ascii_uppercase());
+    if has_lower && has_upper {
+        return None;
+    }
+
+    let s = s.to_ascii_lowercase();
+    let sep = s.rfind('1')?;
+    let (hrp, data) = (&s[..sep], &s[sep + 1..]);
+    if hrp.is_empty() || data.len() < 6 {
+        return None;
+    }
+
+    let values = data
+        .bytes()
+        .map(|c| CHARSET.iter().position(|&x| x == c).map(|p| p as u8))
+        .collect::<Option<Vec<u8>>>()?;
+
+    let variant = match polymod(hrp_expand(hrp.as_bytes()).chain(values.iter().copied())) {
+        BECH32_CONST => Variant::Bech32,
+        BECH32M_CONST => Variant::Bech32m,
+        _ => return None,
+    };
+
+    Some(Decoded {
+        hrp: hrp.to_string(),
+        data: values[..values.len() - 6].to_vec(),
+        variant,
+    })
+}
+
+/// Regroups 5-bit data words into bytes, rejecting leftover bits that are
+/// not zero padding.
+pub fn to_bytes(data: &[u8]) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(data.len() * 5 / 8);
+    let (mut acc, mut bits) = (0u32, 0u32);
+    for &word in data {
+        acc = (acc << 5) | word as u32;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((acc >> bits) as u8);
+            acc &= (1 << bits) - 1;
+        }
+    }
+    (bits < 5 && acc == 0).then_some(bytes)
+}
+
+// --- HRP Registry ---
+
+/// Human-readable parts recognized out of the box, mapped to their chain.
+/// Cosmos SDK chains all share one address format and differ only by HRP,
+/// so supporting another one is a registry entry rather than a new pattern.
+const BUILTIN_HRPS: &[(&str, &str)] = &[
+    ("bc", "bitcoin"),
+    ("tb", "bitcoin"),
+    ("bcrt", "bitcoin"),
+    ("ltc", "litecoin"),
+    ("cosmos", "cosmoshub"),
+    ("osmo", "osmosis"),
+    ("juno", "juno"),
+    ("celestia", "celestia"),
+    ("stars", "stargaze"),
+    ("akash", "akash"),
+    ("axelar", "axelar"),
+    ("inj", "injective"),
+    ("sei", "sei"),
+    ("dydx", "dydx"),
+    ("neutron", "neutron"),
+    ("kava", "kava"),
+    ("secret", "secret"),
+    ("evmos", "evmos"),
+    ("terra", "terra"),
+    ("noble", "noble"),
+    ("stride", "stride"),
+];
+
+static HRP_REGISTRY: OnceLock<RwLock<BTreeMap<String, String>>> = OnceLock::new();
+
+fn registry() -> &'static RwLock<BTreeMap<String, String>> {
+    HRP_REGISTRY.get_or_init(|| {
+        let builtins = BUILTIN_HRPS
+            .iter()
+            .map(|(hrp, chain)| (hrp.to_string(), chain.to_string()));
+        RwLock::new(builtins.collect())
+    })
+}
+
+/// Maps an HRP to a chain at runtime, adding support for new or private
+/// networks (or remapping a built-in one) without a new release.
+pub fn register_hrp(hrp: &str, chain: &str) {
+    registry()
+        .write()
+        .unwrap()
+        .insert(hrp.to_ascii_lowercase(), chain.to_string());
+}
+
+/// Returns the chain registered for an HRP.
+pub fn chain_for_hrp(hrp: &str) -> Option<String> {
+    registry()
+        .read()
+        .unwrap()
+        .get(&hrp.to_ascii_lowercase())
+        .cloned()
+}
+
+/// Snapshot of the current HRP → chain table.
+pub fn registered_hrps() -> BTreeMap<String, String> {
+    registry().read().unwrap().clone()
+}
+
+/// Decodes an address and resolves its HRP to a registered chain.
+pub fn resolve_chain(address: &str) -> Option<String> {
+    chain_for_hrp(&decode(address)?.hrp)
+}
+
+/// Records the HRP and its registered chain on every generic bech32 match,
+/// full or truncated, so consumers can resolve the chain without decoding
+/// the address again.
+pub(crate) fn describe_hrps(matches: &mut [Match]) {
+    for m in matches.iter_mut() {
+        let (hrp, chain) = if m.rule_id == ADDRESS.rule_id {
+            let address = m.metadata.get("address").unwrap_or(&m.value);
+            let Some(decoded) = decode(address) else {
+                continue;
+            };
+            let chain = chain_for_hrp(&decoded.hrp);
+            (decoded.hrp, chain)
+        } else if m.rule_id == ADDRESS_TRUNCATED.rule_id {
+            let Some((hrp, chain)) = truncated_prefix(&m.value) else {
+                continue;
+            };
+            (hrp, Some(chain))
+        } else {
+            continue;
+        };
+        if let Some(chain) = chain {
+            m.metadata.insert("chain".to_string(), chain);
+        }
+        m.metadata.insert("hrp".to_string(), hrp);
+    }
+}
+
+/// Validator for the generic bech32 pattern: the checksum must verify and
+/// the HRP must be registered.
+fn is_registered_address(value: &str) -> bool {
+    resolve_chain(value).is_some()
+}
+
+// --- Generic Address Pattern ---
+
+const BECH32_SRC: &str = r"(?i)\b[a-z0-9]{1,20}1[qpzry9x8gf2tvdw0s3jn54khce6mua7l]{6,110}\b";
+
+static BECH32_RE: OnceLock<Regex> = OnceLock::new();
+
+fn get_bech32_re() -> &'static Regex {
+    BECH32_RE.get_or_init(|| Regex::new(BECH32_SRC).unwrap())
+}
+
+pub(crate) const ADDRESS: Pattern = Pattern {
+    type_: "bech32_address",
+    rule_id: "bech32.address",
+    // Resolved per match through the HRP registry.
+    chain: "bech32",
+    source: BECH32_SRC,
+    regex: get_bech32_re,
+    validate: Some(is_registered_address),
+};
+
+// --- Generic Truncated Pattern ---
+
+/// Prefixes of compiled families that have no truncated pattern of their
+/// own, with their separator and chain. Together with the HRP registry this
+/// is the table the generic truncated pattern accepts, so a chain gets
+/// truncated support from a registry entry or a row here, not a new regex.
+const FAMILY_PREFIXES: &[(&str, char, &str)] = &[
+    #[cfg(feature = "zcash")]
+    ("zs", '1', "zcash"),
+    #[cfg(feature = "avalanche")]
+    ("avax", '1', "avalanche"),
+    #[cfg(feature = "avalanche")]
+    ("fuji", '1', "avalanche"),
+    #[cfg(feature = "chia")]
+    ("xch", '1', "chia"),
+    #[cfg(feature = "chia")]
+    ("txch", '1', "chia"),
+    #[cfg(feature = "harmony")]
+    ("one", '1', "harmony"),
+    #[cfg(feature = "multiversx")]
+    ("erd", '1', "multiversx"),
+    #[cfg(feature = "bitcoincash")]
+    ("bitcoincash", ':', "bitcoincash"),
+    #[cfg(feature = "bitcoincash")]
+    ("bchtest", ':', "bitcoincash"),
+    #[cfg(feature = "kaspa")]
+    ("kaspa", ':', "kaspa"),
+    #[cfg(feature = "kaspa")]
+    ("kaspatest", ':', "kaspa"),
+];
+
+const TRUNCATED_SRC: &str = r"(?i)\b[a-z][a-z0-9]{0,19}[1:][qpzry9x8gf2tvdw0s3jn54khce6mua7l]{3,12}(?:\.{3}|…)[qpzry9x8gf2tvdw0s3jn54khce6mua7l]{4,12}\b";
+
+static TRUNCATED_RE: OnceLock<Regex> = OnceLock::new();
+
+fn get_truncated_re() -> &'static Regex {
+    TRUNCATED_RE.get_or_init(|| Regex::new(TRUNCATED_SRC).unwrap())
+}
+
+/// Splits a truncated value's prefix at its separator (the first `:`, else
+/// the last `1` before the ellipsis) and looks it up in the registry or
+/// `FAMILY_PREFIXES`. Returns the lowercase prefix and its chain.
+fn truncated_prefix(value: &str) -> Option<(String, String)> {
+    let has_lower = value.bytes().any(|b| b.is_ascii_lowercase());
+    let has_upper = value.bytes().any(|b| b.is_ascii_uppercase());
+    if has_lower && has_upper {
+        return None;
+    }
+    let head = value.split(['.', '…']).next()?.to_ascii_lowercase();
+    let (hrp, sep) = match head.split_once(':') {
+        Some((hrp, _)) => (hrp, ':'),
+        None => (head.rsplit_once('1')?.0, '1'),
+    };
+    let family = FAMILY_PREFIXES
+        .iter()
+        .find(|(prefix, s, _)| *prefix == hrp && *s == sep)
+        .map(|(_, _, chain)| chain.to_string());
+    let chain = match sep {
+        '1' => family.or_else(|| chain_for_hrp(hrp)),
+        _ => family,
+    }?;
+    Some((hrp.to_string(), chain))
+}
+
+fn is_known_truncated(value: &str) -> bool {
+    truncated_prefix(value).is_some()
+}
+
+pub(crate) const ADDRESS_TRUNCATED: Pattern = Pattern {
+    type_: "bech32_truncated",
+    rule_id: "bech32.address.truncated",
+    // Resolved per match from the prefix table.
+    chain: "bech32",
+    source: TRUNCATED_SRC,
+    regex: get_truncated_re,
+    validate: Some(is_known_truncated),
+};
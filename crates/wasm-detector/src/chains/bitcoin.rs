@@ -0,0 +1,136 @@
+//! Bitcoin addresses. SegWit ones are split by witness version: v0
+//! (`bc1q…`, P2WPKH and P2WSH) uses the bech32 checksum and v1 Taproot
+//! (`bc1p…`) uses bech32m (BIP-350). Each kind has its own program length,
+//! and an address whose checksum flavor does not match its version is
+//! rejected. Testnet (`tb1`) and regtest (`bcrt1`) HRPs are accepted too, as
+//! are Base58Check testnet P2PKH (`m…`/`n…`) and P2SH (`2…`) addresses;
+//! `describe_networks` records which network each one belongs to. Ordinals
+//! inscription ids (`<txid>i<index>`) are reported whole, not as a bare
+//! transaction id.
+
+use crate::bech32::{self, Variant};
+use crate::chains::base58check;
+use crate::{Match, Pattern};
+use regex::Regex;
+use std::sync::OnceLock;
+
+// 20-byte (42 chars) or 32-byte (62 chars) witness programs.
+const SEGWIT_SRC: &str = r"(?i)\b(?:bc|tb|bcrt)1q[qpzry9x8gf2tvdw0s3jn54khce6mua7l]{38}(?:[qpzry9x8gf2tvdw0s3jn54khce6mua7l]{20})?\b";
+const TAPROOT_SRC: &str = r"(?i)\b(?:bc|tb|bcrt)1p[qpzry9x8gf2tvdw0s3jn54khce6mua7l]{58}\b";
+const LEGACY_SRC: &str = r"\b[mn2][1-9A-HJ-NP-Za-km-z]{25,34}\b";
+const INSCRIPTION_SRC: &str = r"\b[0-9a-f]{64}i(?:0|[1-9][0-9]{0,9})\b";
+
+/// SegWit HRPs and the network each one names.
+const HRPS: &[(&str, &str)] = &[("bc", "mainnet"), ("tb", "testnet"), ("bcrt", "regtest")];
+
+/// Version bytes of testnet P2PKH (`m`/`n`) and P2SH (`2`) addresses.
+/// Regtest reuses them, so the two cannot be told apart.
+const TESTNET_VERSIONS: [u8; 2] = [0x6f, 0xc4];
+
+static SEGWIT_RE: OnceLock<Regex> = OnceLock::new();
+static TAPROOT_RE: OnceLock<Regex> = OnceLock::new();
+static LEGACY_RE: OnceLock<Regex> = OnceLock::new();
+static INSCRIPTION_RE: OnceLock<Regex> = OnceLock::new();
+
+fn get_segwit_re() -> &'static Regex {
+    SEGWIT_RE.get_or_init(|| Regex::new(SEGWIT_SRC).unwrap())
+}
+
+fn get_taproot_re() -> &'static Regex {
+    TAPROOT_RE.get_or_init(|| Regex::new(TAPROOT_SRC).unwrap())
+}
+
+fn get_legacy_re() -> &'static Regex {
+    LEGACY_RE.get_or_init(|| Regex::new(LEGACY_SRC).unwrap())
+}
+
+fn get_inscription_re() -> &'static Regex {
+    INSCRIPTION_RE.get_or_init(|| Regex::new(INSCRIPTION_SRC).unwrap())
+}
+
+fn network_for_hrp(hrp: &str) -> Option<&'static str> {
+    HRPS.iter().find(|(h, _)| *h == hrp).map(|(_, n)| *n)
+}
+
+/// Witness version and program length in bytes, if the checksum verifies
+/// with the flavor `variant`.
+fn witness(value: &str, variant: Variant) -> Option<(u8, usize)> {
+    let decoded = bech32::decode(value)
+        .filter(|d| network_for_hrp(&d.hrp).is_some() && d.variant == variant)?;
+    let (&version, program) = decoded.data.split_first()?;
+    Some((version, program.len() * 5 / 8))
+}
+
+fn is_valid_segwit(value: &str) -> bool {
+    matches!(witness(value, Variant::Bech32), Some((0, 20 | 32)))
+}
+
+fn is_valid_taproot(value: &str) -> bool {
+    matches!(witness(value, Variant::Bech32m), Some((1, 32)))
+}
+
+fn is_valid_legacy(value: &str) -> bool {
+    base58check::decode(value)
+        .is_some_and(|payload| payload.len() == 21 && TESTNET_VERSIONS.contains(&payload[0]))
+}
+
+/// The output index must fit the `u32` of a transaction input.
+fn is_valid_inscription(value: &str) -> bool {
+    value[65..].parse::<u32>().is_ok()
+}
+
+/// Sets `network` on every Bitcoin match: from the HRP for SegWit
+/// addresses, and `testnet` for Base58 ones.
+pub(crate) fn describe_networks(matches: &mut [Match]) {
+    for m in matches.iter_mut() {
+        let network = if m.rule_id == LEGACY_ADDRESS.rule_id {
+            Some("testnet")
+        } else if m.rule_id == SEGWIT_ADDRESS.rule_id || m.rule_id == TAPROOT_ADDRESS.rule_id {
+            let lower = m.value.to_ascii_lowercase();
+            lower
+                .rsplit_once('1')
+                .and_then(|(hrp, _)| network_for_hrp(hrp))
+        } else {
+            None
+        };
+        if let Some(network) = network {
+            m.network = Some(network.to_string());
+        }
+    }
+}
+
+pub(crate) const SEGWIT_ADDRESS: Pattern = Pattern {
+    type_: "btc_segwit_address",
+    rule_id: "bitcoin.segwit_address",
+    chain: "bitcoin",
+    source: SEGWIT_SRC,
+    regex: get_segwit_re,
+    validate: Some(is_valid_segwit),
+};
+
+pub(crate) const TAPROOT_ADDRESS: Pattern = Pattern {
+    type_: "btc_taproot_address",
+    rule_id: "bitcoin.taproot_address",
+    chain: "bitcoin",
+    source: TAPROOT_SRC,
+    regex: get_taproot_re,
+    validate: Some(is_valid_taproot),
+};
+
+pub(crate) const LEGACY_ADDRESS: Pattern = Pattern {
+    type_: "btc_legacy_address",
+    rule_id: "bitcoin.legacy_address",
+    chain: "bitcoin",
+    source: LEGACY_SRC,
+    regex: get_legacy_re,
+    validate: Some(is_valid_legacy),
+};
+
+pub(crate) const INSCRIPTION: Pattern = Pattern {
+    type_: "btc_inscription",
+    rule_id: "bitcoin.inscription",
+    chain: "bitcoin",
+    source: INSCRIPTION_SRC,
+    regex: get_inscription_re,
+    validate: Some(is_valid_inscription),
+};
@@ -0,0 +1,87 @@
+//! Bitcoin Cash cashaddr addresses: `bitcoincash:` (or testnet `bchtest:`)
+//! followed by a bech32-charset payload whose 40-bit BCH checksum covers the
+//! prefix. P2PKH payloads start with `q`, P2SH with `p`. The prefix is often
+//! omitted; such bare payloads are checked against the mainnet prefix and
+//! kept only when `gate_prefixless` finds a Bitcoin Cash word nearby.
+
+use crate::chains::cashaddr;
+use crate::{Match, Pattern, grapheme};
+use regex::Regex;
+use std::sync::OnceLock;
+
+const ADDRESS_SRC: &str = r"(?i)\b(?:(?:bitcoincash|bchtest):)?q[qpzry9x8gf2tvdw0s3jn54khce6mua7l]{41}(?:[qpzry9x8gf2tvdw0s3jn54khce6mua7l]{19})?\b";
+const SCRIPT_ADDRESS_SRC: &str = r"(?i)\b(?:(?:bitcoincash|bchtest):)?p[qpzry9x8gf2tvdw0s3jn54khce6mua7l]{41}(?:[qpzry9x8gf2tvdw0s3jn54khce6mua7l]{19})?\b";
+
+const DEFAULT_PREFIX: &str = "bitcoincash";
+
+/// Grapheme clusters on either side of a bare payload searched for context.
+const CONTEXT_WINDOW: usize = 48;
+const CONTEXT_WORDS: &[&str] = &["bch", "bitcoincash", "cash", "cashaddr"];
+
+static ADDRESS_RE: OnceLock<Regex> = OnceLock::new();
+static SCRIPT_ADDRESS_RE: OnceLock<Regex> = OnceLock::new();
+
+fn get_address_re() -> &'static Regex {
+    ADDRESS_RE.get_or_init(|| Regex::new(ADDRESS_SRC).unwrap())
+}
+
+fn get_script_address_re() -> &'static Regex {
+    SCRIPT_ADDRESS_RE.get_or_init(|| Regex::new(SCRIPT_ADDRESS_SRC).unwrap())
+}
+
+/// Verifies the checksum and that the version byte's size bits match the
+/// payload length (160-bit hashes in 42 characters, 256-bit in 61).
+fn is_valid_cashaddr(value: &str) -> bool {
+    let has_lower = value.bytes().any(|b| b.is_ascii_lowercase());
+    let has_upper = value.bytes().any(|b| b.is_ascii_uppercase());
+    if has_lower && has_upper {
+        return false;
+    }
+    let value = value.to_ascii_lowercase();
+    let (prefix, payload) = value.split_once(':').unwrap_or((DEFAULT_PREFIX, &value));
+    let Some(words) = cashaddr::decode(prefix, payload) else {
+        return false;
+    };
+    // The second word holds the low bits of the version byte: size code.
+    let size_code = (words[1] >> 2) & 0b111;
+    matches!((payload.len(), size_code), (42, 0) | (61, 3))
+}
+
+fn has_context(text: &str, m: &Match) -> bool {
+    let end = m.index + m.value.len();
+    [
+        grapheme::before(text, m.index, CONTEXT_WINDOW),
+        grapheme::after(text, end, CONTEXT_WINDOW),
+    ]
+    .iter()
+    .any(|side| {
+        side.split(|c: char| !c.is_ascii_alphanumeric())
+            .any(|w| CONTEXT_WORDS.contains(&w.to_ascii_lowercase().as_str()))
+    })
+}
+
+/// Drops prefixless cashaddr matches with no Bitcoin Cash word nearby.
+pub(crate) fn gate_prefixless(text: &str, matches: &mut Vec<Match>) {
+    let rules = [ADDRESS.rule_id, SCRIPT_ADDRESS.rule_id];
+    matches.retain(|m| {
+        !rules.contains(&m.rule_id.as_str()) || m.value.contains(':') || has_context(text, m)
+    });
+}
+
+pub(crate) const ADDRESS: Pattern = Pattern {
+    type_: "bch_address",
+    rule_id: "bitcoincash.address",
+    chain: "bitcoincash",
+    source: ADDRESS_SRC,
+    regex: get_address_re,
+    validate: Some(is_valid_cashaddr),
+};
+
+pub(crate) const SCRIPT_ADDRESS: Pattern = Pattern {
+    type_: "bch_script_address",
+    rule_id: "bitcoincash.script_address",
+    chain: "bitcoincash",
+    source: SCRIPT_ADDRESS_SRC,
+    regex: get_script_address_re,
+    validate: Some(is_valid_cashaddr),
+};
@@ -0,0 +1,78 @@
+//! Cardano Shelley payment (`addr1...`) and stake (`stake1...`) addresses,
+//! bech32-encoded and checksum-verified. Testnet forms use `addr_test` and
+//! `stake_test`.
+
+use crate::Pattern;
+use crate::bech32;
+use regex::Regex;
+use std::sync::OnceLock;
+
+const ADDRESS_SRC: &str = r"(?i)\baddr(?:_test)?1[qpzry9x8gf2tvdw0s3jn54khce6mua7l]{50,}\b";
+const ADDRESS_TRUNCATED_SRC: &str = r"(?i)\baddr(?:_test)?1[qpzry9x8gf2tvdw0s3jn54khce6mua7l]{4,12}(?:\.{3}|…)[qpzry9x8gf2tvdw0s3jn54khce6mua7l]{4,12}\b";
+const STAKE_SRC: &str = r"(?i)\bstake(?:_test)?1[qpzry9x8gf2tvdw0s3jn54khce6mua7l]{50,}\b";
+const STAKE_TRUNCATED_SRC: &str = r"(?i)\bstake(?:_test)?1[qpzry9x8gf2tvdw0s3jn54khce6mua7l]{4,12}(?:\.{3}|…)[qpzry9x8gf2tvdw0s3jn54khce6mua7l]{4,12}\b";
+
+static ADDRESS_RE: OnceLock<Regex> = OnceLock::new();
+static ADDRESS_TRUNCATED_RE: OnceLock<Regex> = OnceLock::new();
+static STAKE_RE: OnceLock<Regex> = OnceLock::new();
+static STAKE_TRUNCATED_RE: OnceLock<Regex> = OnceLock::new();
+
+fn get_address_re() -> &'static Regex {
+    ADDRESS_RE.get_or_init(|| Regex::new(ADDRESS_SRC).unwrap())
+}
+
+fn get_address_truncated_re() -> &'static Regex {
+    ADDRESS_TRUNCATED_RE.get_or_init(|| Regex::new(ADDRESS_TRUNCATED_SRC).unwrap())
+}
+
+fn get_stake_re() -> &'static Regex {
+    STAKE_RE.get_or_init(|| Regex::new(STAKE_SRC).unwrap())
+}
+
+fn get_stake_truncated_re() -> &'static Regex {
+    STAKE_TRUNCATED_RE.get_or_init(|| Regex::new(STAKE_TRUNCATED_SRC).unwrap())
+}
+
+fn is_valid_address(value: &str) -> bool {
+    bech32::decode(value).is_some_and(|d| d.hrp == "addr" || d.hrp == "addr_test")
+}
+
+fn is_valid_stake_address(value: &str) -> bool {
+    bech32::decode(value).is_some_and(|d| d.hrp == "stake" || d.hrp == "stake_test")
+}
+
+pub(crate) const ADDRESS: Pattern = Pattern {
+    type_: "ada_address",
+    rule_id: "cardano.address",
+    chain: "cardano",
+    source: ADDRESS_SRC,
+    regex: get_address_re,
+    validate: Some(is_valid_address),
+};
+
+pub(crate) const ADDRESS_TRUNCATED: Pattern = Pattern {
+    type_: "ada_truncated",
+    rule_id: "cardano.address.truncated",
+    chain: "cardano",
+    source: ADDRESS_TRUNCATED_SRC,
+    regex: get_address_truncated_re,
+    validate: None,
+};
+
+pub(crate) const STAKE_ADDRESS: Pattern = Pattern {
+    type_: "ada_stake_address",
+    rule_id: "cardano.stake_address",
+    chain: "cardano",
+    source: STAKE_SRC,
+    regex: get_stake_re,
+    validate: Some(is_valid_stake_address),
+};
+
+pub(crate) const STAKE_ADDRESS_TRUNCATED: Pattern = Pattern {
+    type_: "ada_stake_truncated",
+    rule_id: "cardano.stake_address.truncated",
+    chain: "cardano",
+    source: STAKE_TRUNCATED_SRC,
+    regex: get_stake_truncated_re,
+    validate: None,
+};
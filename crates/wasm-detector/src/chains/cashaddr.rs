@@ -0,0 +1,36 @@
+//! The cashaddr checksum (a 40-bit BCH code over the prefix and payload),
+//! shared by Bitcoin Cash and Kaspa, which adopted the same format.
+
+const CHARSET: &[u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const GENERATOR: [u64; 5] = [
+    0x98_f2bc_8e61,
+    0x79_b76d_99e2,
+    0xf3_3e5f_b3c4,
+    0xae_2eab_e2a8,
+    0x1e_4f43_e470,
+];
+
+fn polymod(values: impl Iterator<Item = u8>) -> u64 {
+    let mut c: u64 = 1;
+    for v in values {
+        let top = c >> 35;
+        c = ((c & 0x07_ffff_ffff) << 5) ^ v as u64;
+        for (i, g) in GENERATOR.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                c ^= g;
+            }
+        }
+    }
+    c ^ 1
+}
+
+/// Decodes a lowercase payload into 5-bit words (checksum included) if its
+/// checksum verifies over `prefix`.
+pub(crate) fn decode(prefix: &str, payload: &str) -> Option<Vec<u8>> {
+    let words = payload
+        .bytes()
+        .map(|c| CHARSET.iter().position(|&x| x == c).map(|p| p as u8))
+        .collect::<Option<Vec<u8>>>()?;
+    let prefix_words = prefix.bytes().map(|c| c & 31).chain(std::iter::once(0));
+    (polymod(prefix_words.chain(words.iter().copied())) == 0).then_some(words)
+}
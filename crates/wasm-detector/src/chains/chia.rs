@@ -0,0 +1,46 @@
+//! Chia addresses: a 32-byte puzzle hash, bech32m-encoded under the `xch`
+//! (mainnet) or `txch` (testnet) HRP. Truncated forms are covered by the
+//! generic bech32 truncated pattern.
+
+use crate::bech32;
+use crate::{Match, Pattern};
+use regex::Regex;
+use std::sync::OnceLock;
+
+const ADDRESS_SRC: &str = r"(?i)\bt?xch1[qpzry9x8gf2tvdw0s3jn54khce6mua7l]{58}\b";
+
+static ADDRESS_RE: OnceLock<Regex> = OnceLock::new();
+
+fn get_address_re() -> &'static Regex {
+    ADDRESS_RE.get_or_init(|| Regex::new(ADDRESS_SRC).unwrap())
+}
+
+/// The checksum must be bech32m and the payload a 32-byte (52-word) hash.
+fn is_valid_address(value: &str) -> bool {
+    bech32::decode(value).is_some_and(|d| {
+        d.variant == bech32::Variant::Bech32m
+            && matches!(d.hrp.as_str(), "xch" | "txch")
+            && d.data.len() == 52
+    })
+}
+
+/// Records each address's `network` from its HRP.
+pub(crate) fn describe_networks(matches: &mut [Match]) {
+    for m in matches.iter_mut().filter(|m| m.rule_id == ADDRESS.rule_id) {
+        let network = if m.value[..1].eq_ignore_ascii_case("t") {
+            "testnet"
+        } else {
+            "mainnet"
+        };
+        m.network = Some(network.to_string());
+    }
+}
+
+pub(crate) const ADDRESS: Pattern = Pattern {
+    type_: "xch_address",
+    rule_id: "chia.address",
+    chain: "chia",
+    source: ADDRESS_SRC,
+    regex: get_address_re,
+    validate: Some(is_valid_address),
+};
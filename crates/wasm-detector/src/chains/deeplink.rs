@@ -0,0 +1,64 @@
+//! Wallet deep links: ERC-681 `ethereum:` payment URIs, mobile wallet
+//! schemes (`metamask://`, `trust://`, `phantom://`, ...) and WalletConnect
+//! `wc:` pairing URIs. A shared link leaks the same data as the addresses it
+//! embeds, so the whole link is one match.
+
+use crate::{DetectorOptions, Match, Pattern, collect_matches_with_options};
+use regex::Regex;
+use std::sync::OnceLock;
+
+const DEEP_LINK_SRC: &str = r#"(?i)\b(?:ethereum:|(?:metamask|trust|phantom|rainbow|cbwallet|wc)://|wc:[0-9a-f]{8,}@)[^\s"'<>]*[^\s"'<>.,;:!?)\]]"#;
+
+static DEEP_LINK_RE: OnceLock<Regex> = OnceLock::new();
+
+fn get_deep_link_re() -> &'static Regex {
+    DEEP_LINK_RE.get_or_init(|| Regex::new(DEEP_LINK_SRC).unwrap())
+}
+
+pub(crate) const WALLET_LINK: Pattern = Pattern {
+    type_: "deep_link",
+    rule_id: "deeplink.wallet",
+    chain: "multi",
+    source: DEEP_LINK_SRC,
+    regex: get_deep_link_re,
+    validate: None,
+};
+
+/// Splits a link's query string into `(key, value)` pairs.
+fn query_params(link: &str) -> impl Iterator<Item = (&str, &str)> {
+    let query = link.split_once('?').map_or("", |(_, q)| q);
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| pair.split_once('=').unwrap_or((pair, "")))
+}
+
+/// Records each deep link's scheme, query parameters (`param.<key>`) and the
+/// addresses, hashes and names embedded in it (`embedded`, comma-separated).
+pub(crate) fn describe_links(matches: &mut [Match]) {
+    let inner = DetectorOptions {
+        disabled: vec![WALLET_LINK.type_.to_string()],
+        ..DetectorOptions::default()
+    };
+
+    for m in matches.iter_mut().filter(|m| m.type_ == WALLET_LINK.type_) {
+        let scheme = m.value.split(':').next().unwrap_or_default();
+        m.metadata
+            .insert("scheme".to_string(), scheme.to_ascii_lowercase());
+
+        for (key, value) in query_params(&m.value) {
+            m.metadata.insert(format!("param.{key}"), value.to_string());
+        }
+
+        let embedded: Vec<String> = collect_matches_with_options(&m.value, &inner)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|e| e.type_ != "chain_id")
+            .map(|e| e.value)
+            .collect();
+        if !embedded.is_empty() {
+            m.metadata
+                .insert("embedded".to_string(), embedded.join(","));
+        }
+    }
+}
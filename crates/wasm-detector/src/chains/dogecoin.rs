@@ -0,0 +1,29 @@
+//! Dogecoin P2PKH addresses: `D` + Base58 characters, Base58Check-encoded
+//! with version byte `0x1e`. Checked ahead of the generic Base58 patterns,
+//! which would otherwise report them as Solana addresses.
+
+use crate::Pattern;
+use crate::chains::base58check;
+use regex::Regex;
+use std::sync::OnceLock;
+
+const DOGE_SRC: &str = r"\bD[1-9A-HJ-NP-Za-km-z]{25,33}\b";
+
+static DOGE_RE: OnceLock<Regex> = OnceLock::new();
+
+fn get_doge_re() -> &'static Regex {
+    DOGE_RE.get_or_init(|| Regex::new(DOGE_SRC).unwrap())
+}
+
+fn is_valid_doge(value: &str) -> bool {
+    base58check::decode(value).is_some_and(|payload| payload.len() == 21 && payload[0] == 0x1e)
+}
+
+pub(crate) const ADDRESS: Pattern = Pattern {
+    type_: "doge_address",
+    rule_id: "dogecoin.address",
+    chain: "dogecoin",
+    source: DOGE_SRC,
+    regex: get_doge_re,
+    validate: Some(is_valid_doge),
+};
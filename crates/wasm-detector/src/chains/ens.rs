@@ -0,0 +1,192 @@
+//! ENS names (`*.eth`), with the host's allowlist (names never reported,
+//! e.g. `this.eth` used as a joke) and denylist (names always reported,
+//! e.g. a known phishing name) applied by `apply_lists`. Namehashes, 32-byte
+//! hex node ids, are relabeled from the hash types they look like by
+//! `relabel_namehashes`. Names served through a gateway (`vitalik.eth.limo`)
+//! are handled as `DetectorOptions::ens_gateways` says.
+
+use crate::compare::compare_key;
+use crate::{DetectorOptions, Match, Pattern, grapheme, has_overlap};
+use regex::Regex;
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+const ENS_SRC: &str = r"(?i)\b[a-z0-9](?:[a-z0-9-]{0,61}[a-z0-9])?\.eth\b";
+
+static ENS_RE: OnceLock<Regex> = OnceLock::new();
+
+fn get_ens_re() -> &'static Regex {
+    ENS_RE.get_or_init(|| Regex::new(ENS_SRC).unwrap())
+}
+
+fn is_valid_ens(text: &str) -> bool {
+    if !text.ends_with(".eth") {
+        return false;
+    }
+    if text.len() <= 7 {
+        return text.len() > 4;
+    }
+    true
+}
+
+/// Whether `text[start..end]` stands alone rather than inside a longer
+/// name or word.
+fn is_whole_word(text: &str, start: usize, end: usize) -> bool {
+    let is_name_char = |c: char| c.is_alphanumeric() || c == '-' || c == '_';
+    let before = text[..start].chars().next_back();
+    let after = text[end..].chars().next();
+    !before.is_some_and(|c| is_name_char(c) || c == '.')
+        && !after.is_some_and(|c| {
+            is_name_char(c) || c == '.' && text[end + 1..].starts_with(is_name_char)
+        })
+}
+
+/// Drops names on `options.ens_allowlist` and reports every whole-word
+/// occurrence of a name on `options.ens_denylist` that no other match
+/// covers, tagged `denylisted`. Both compare by `compare_key`.
+pub(crate) fn apply_lists(text: &str, matches: &mut Vec<Match>, options: &DetectorOptions) {
+    if options.disabled.iter().any(|t| t == NAME.type_) {
+        return;
+    }
+    let key = |name: &str| compare_key(NAME.rule_id, name.trim());
+    let allowed: Vec<String> = options.ens_allowlist.iter().map(|n| key(n)).collect();
+    matches.retain(|m| m.rule_id != NAME.rule_id || !allowed.contains(&key(&m.value)));
+
+    let folded = text.to_ascii_lowercase();
+    for name in options.ens_denylist.iter().map(|n| key(n)) {
+        if name.is_empty() || allowed.contains(&name) {
+            continue;
+        }
+        let hits: Vec<usize> = folded
+            .match_indices(name.as_str())
+            .map(|(i, _)| i)
+            .collect();
+        for start in hits {
+            let end = start + name.len();
+            if !is_whole_word(text, start, end) {
+                continue;
+            }
+            if let Some(m) = matches
+                .iter_mut()
+                .find(|m| m.index == start && m.value.len() == name.len())
+            {
+                m.metadata
+                    .insert("denylisted".to_string(), "true".to_string());
+                continue;
+            }
+            if has_overlap(start, end, matches) {
+                continue;
+            }
+            let mut m = NAME.to_match(&text[start..end], start);
+            m.metadata = BTreeMap::from([("denylisted".to_string(), "true".to_string())]);
+            matches.push(m);
+        }
+    }
+}
+
+// --- Namehashes ---
+
+/// Grapheme clusters before a value searched for context words.
+const CONTEXT_WINDOW: usize = 32;
+
+/// Words, and JSON keys such as `"node"` or `"parentNode"`, naming a namehash.
+const NAMEHASH_WORDS: &[&str] = &["namehash", "node", "parentnode", "subnode"];
+const TX_WORDS: &[&str] = &["tx", "txn", "txid", "txhash", "transaction"];
+
+/// Match type and rule id for 32-byte hex values used as ENS nodes.
+const NAMEHASH: (&str, &str) = ("ens_namehash", "ens.namehash");
+
+fn is_hex32(value: &str) -> bool {
+    let hex = value.strip_prefix("0x").unwrap_or(value);
+    hex.len() == 64 && hex.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+/// Relabels 32-byte hex matches (transaction hashes, implicit accounts) as
+/// `ens_namehash` when a namehash word sits closer before them than any
+/// transaction word.
+pub(crate) fn relabel_namehashes(text: &str, matches: &mut [Match], options: &DetectorOptions) {
+    if options.disabled.iter().any(|t| t == NAMEHASH.0) {
+        return;
+    }
+    for m in matches.iter_mut().filter(|m| is_hex32(&m.value)) {
+        let words: Vec<String> = grapheme::before(text, m.index, CONTEXT_WINDOW)
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|w| !w.is_empty())
+            .map(str::to_ascii_lowercase)
+            .collect();
+        let nearest = |set: &[&str]| words.iter().rev().position(|w| set.contains(&w.as_str()));
+        let is_namehash = match (nearest(NAMEHASH_WORDS), nearest(TX_WORDS)) {
+            (Some(n), Some(t)) => n < t,
+            (named, _) => named.is_some(),
+        };
+        if is_namehash {
+            m.type_ = NAMEHASH.0.to_string();
+            m.rule_id = NAMEHASH.1.to_string();
+        }
+    }
+}
+
+// --- Gateway URLs ---
+
+/// Hosts that serve an ENS name's content at `<name>.eth.<gateway>`.
+const GATEWAYS: &[&str] = &["limo", "link"];
+
+/// Match type and rule id for whole gateway URLs.
+const GATEWAY_URL: (&str, &str) = ("ens_gateway_url", "ens.gateway_url");
+
+/// Length of the gateway host after a name ending at `end` (`.limo`), if
+/// the name is followed by one.
+fn gateway_after(text: &str, end: usize) -> Option<usize> {
+    let rest = text[end..].strip_prefix('.')?;
+    GATEWAYS.iter().find_map(|gateway| {
+        let host = rest.get(..gateway.len())?;
+        let after = rest[gateway.len()..].chars().next();
+        let ends = !after.is_some_and(|c| c.is_alphanumeric() || c == '-' || c == '.');
+        (host.eq_ignore_ascii_case(gateway) && ends).then_some(1 + gateway.len())
+    })
+}
+
+/// Applies `options.ens_gateways` to names followed by a gateway host:
+/// `"name"` (the default) keeps reporting just the name, `"ignore"` drops
+/// it, and `"url"` reports the scheme, name and gateway host as one
+/// `ens_gateway_url` match with the name in `metadata.name`. A path stays
+/// outside the match so values in it are still reported on their own.
+pub(crate) fn apply_gateways(text: &str, matches: &mut Vec<Match>, options: &DetectorOptions) {
+    let mode = options.ens_gateways.as_deref().unwrap_or("name");
+    if !matches!(mode, "ignore" | "url") {
+        return;
+    }
+    matches.retain_mut(|m| {
+        let end = m.index + m.value.len();
+        let Some(host_len) = gateway_after(text, end).filter(|_| m.rule_id == NAME.rule_id) else {
+            return true;
+        };
+        if mode != "url" {
+            return false;
+        }
+        let before = &text[..m.index];
+        let start = ["https://", "http://"]
+            .iter()
+            .find(|scheme| {
+                let tail = before.len().checked_sub(scheme.len());
+                tail.and_then(|i| before.get(i..))
+                    .is_some_and(|tail| tail.eq_ignore_ascii_case(scheme))
+            })
+            .map_or(m.index, |scheme| m.index - scheme.len());
+        m.metadata.insert("name".to_string(), m.value.clone());
+        m.value = text[start..end + host_len].to_string();
+        m.index = start;
+        m.type_ = GATEWAY_URL.0.to_string();
+        m.rule_id = GATEWAY_URL.1.to_string();
+        true
+    });
+}
+
+pub(crate) const NAME: Pattern = Pattern {
+    type_: "ens",
+    rule_id: "ens.name",
+    chain: "ethereum",
+    source: ENS_SRC,
+    regex: get_ens_re,
+    validate: Some(is_valid_ens),
+};
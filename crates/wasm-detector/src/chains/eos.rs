@@ -0,0 +1,46 @@
+//! EOS and other Antelope chains' account names: twelve characters from
+//! `a-z` and `1-5`. Plenty of ordinary words fit that shape, so
+//! `gate_accounts` keeps a name only when an EOS word sits nearby.
+
+use crate::{Match, Pattern, grapheme};
+use regex::Regex;
+use std::sync::OnceLock;
+
+const ACCOUNT_SRC: &str = r"\b[a-z1-5]{12}\b";
+
+/// Grapheme clusters on either side of a name searched for context words.
+const CONTEXT_WINDOW: usize = 48;
+
+const CONTEXT_WORDS: &[&str] = &["eos", "antelope", "account", "memo"];
+
+static ACCOUNT_RE: OnceLock<Regex> = OnceLock::new();
+
+fn get_account_re() -> &'static Regex {
+    ACCOUNT_RE.get_or_init(|| Regex::new(ACCOUNT_SRC).unwrap())
+}
+
+fn has_context(text: &str, m: &Match) -> bool {
+    let end = m.index + m.value.len();
+    let window = [
+        grapheme::before(text, m.index, CONTEXT_WINDOW),
+        grapheme::after(text, end, CONTEXT_WINDOW),
+    ];
+    window.iter().any(|side| {
+        side.split(|c: char| !c.is_ascii_alphanumeric())
+            .any(|w| CONTEXT_WORDS.contains(&w.to_ascii_lowercase().as_str()))
+    })
+}
+
+/// Drops account names with no EOS word nearby.
+pub(crate) fn gate_accounts(text: &str, matches: &mut Vec<Match>) {
+    matches.retain(|m| m.rule_id != ACCOUNT.rule_id || has_context(text, m));
+}
+
+pub(crate) const ACCOUNT: Pattern = Pattern {
+    type_: "eos_account",
+    rule_id: "eos.account",
+    chain: "eos",
+    source: ACCOUNT_SRC,
+    regex: get_account_re,
+    validate: None,
+};
@@ -0,0 +1,196 @@
+//! EVM addresses and transaction hashes (`0x` + hex).
+
+use crate::move_address::{nearest, words_before};
+use crate::{Match, Pattern};
+use regex::Regex;
+use std::sync::OnceLock;
+use tiny_keccak::{Hasher, Keccak};
+
+const FULL_ADDRESS_SRC: &str = r"\b0x[a-fA-F0-9]{40}\b";
+const TRUNCATED_SRC: &str = r"\b0x[a-fA-F0-9]{4,12}(?:\.{3}|…)[a-fA-F0-9]{4,12}\b";
+const ETH_TX_HASH_SRC: &str = r"\b0x[a-fA-F0-9]{64}\b";
+const ETH_TX_TRUNCATED_SRC: &str = r"\b0x[a-fA-F0-9]{4,12}(?:\.{3}|…)[a-fA-F0-9]{4,12}\b";
+const CHAIN_ID_SRC: &str =
+    r#"(?i)\b(?:chain[_-]?id["']?\s*[:=]\s*["']?(?:0x[0-9a-f]+|[0-9]+)|eip155:[0-9]+)\b"#;
+
+static FULL_ADDRESS_RE: OnceLock<Regex> = OnceLock::new();
+static TRUNCATED_RE: OnceLock<Regex> = OnceLock::new();
+static ETH_TX_HASH_RE: OnceLock<Regex> = OnceLock::new();
+static ETH_TX_TRUNCATED_RE: OnceLock<Regex> = OnceLock::new();
+static CHAIN_ID_RE: OnceLock<Regex> = OnceLock::new();
+
+fn get_full_address_re() -> &'static Regex {
+    FULL_ADDRESS_RE.get_or_init(|| Regex::new(FULL_ADDRESS_SRC).unwrap())
+}
+
+fn get_truncated_re() -> &'static Regex {
+    TRUNCATED_RE.get_or_init(|| Regex::new(TRUNCATED_SRC).unwrap())
+}
+
+fn get_eth_tx_hash_re() -> &'static Regex {
+    ETH_TX_HASH_RE.get_or_init(|| Regex::new(ETH_TX_HASH_SRC).unwrap())
+}
+
+fn get_eth_tx_truncated_re() -> &'static Regex {
+    ETH_TX_TRUNCATED_RE.get_or_init(|| Regex::new(ETH_TX_TRUNCATED_SRC).unwrap())
+}
+
+fn get_chain_id_re() -> &'static Regex {
+    CHAIN_ID_RE.get_or_init(|| Regex::new(CHAIN_ID_SRC).unwrap())
+}
+
+pub(crate) const TX_HASH: Pattern = Pattern {
+    type_: "eth_tx_hash",
+    rule_id: "evm.tx_hash",
+    chain: "ethereum",
+    source: ETH_TX_HASH_SRC,
+    regex: get_eth_tx_hash_re,
+    validate: None,
+};
+
+pub(crate) const TX_TRUNCATED: Pattern = Pattern {
+    type_: "eth_tx_truncated",
+    rule_id: "evm.tx_hash.truncated",
+    chain: "ethereum",
+    source: ETH_TX_TRUNCATED_SRC,
+    regex: get_eth_tx_truncated_re,
+    validate: None,
+};
+
+pub(crate) const ADDRESS: Pattern = Pattern {
+    type_: "fullAddress",
+    rule_id: "evm.address",
+    chain: "ethereum",
+    source: FULL_ADDRESS_SRC,
+    regex: get_full_address_re,
+    validate: None,
+};
+
+pub(crate) const ADDRESS_TRUNCATED: Pattern = Pattern {
+    type_: "truncated",
+    rule_id: "evm.address.truncated",
+    chain: "ethereum",
+    source: TRUNCATED_SRC,
+    regex: get_truncated_re,
+    validate: None,
+};
+
+/// `chainId: 137`, `chain_id = "0x89"`, `eip155:42161`. The resolved network
+/// name is attached afterwards from the chain registry.
+pub(crate) const CHAIN_ID: Pattern = Pattern {
+    type_: "chain_id",
+    rule_id: "evm.chain_id",
+    chain: "ethereum",
+    source: CHAIN_ID_SRC,
+    regex: get_chain_id_re,
+    validate: None,
+};
+
+// --- Truncated Classification ---
+
+/// Short forms of addresses and transaction hashes look identical, so the
+/// first truncated pattern would claim both. These words decide instead.
+const ADDRESS_WORDS: &[&str] = &[
+    "address",
+    "addr",
+    "account",
+    "wallet",
+    "owner",
+    "from",
+    "to",
+    "sender",
+    "recipient",
+    "contract",
+    "token",
+];
+const TX_WORDS: &[&str] = &["tx", "txn", "txhash", "txid", "transaction", "hash"];
+
+/// Match type for truncated values whose context names neither kind.
+const AMBIGUOUS_TRUNCATED: (&str, &str) = ("ambiguous_truncated", "evm.ambiguous.truncated");
+
+/// Reclassifies truncated `0x…` matches by the nearest address or
+/// transaction word before them. Without one, a value that either enabled
+/// pattern could have produced becomes `ambiguous_truncated`, listing the
+/// candidate types in `metadata.candidates`.
+pub(crate) fn classify_truncated(text: &str, matches: &mut [Match], patterns: &[&Pattern]) {
+    let candidates: Vec<&Pattern> = [&ADDRESS_TRUNCATED, &TX_TRUNCATED]
+        .into_iter()
+        .filter(|c| patterns.iter().any(|p| p.rule_id == c.rule_id))
+        .collect();
+    if candidates.len() < 2 {
+        return;
+    }
+
+    let truncated = |m: &&mut Match| candidates.iter().any(|c| c.rule_id == m.rule_id);
+    for m in matches.iter_mut().filter(truncated) {
+        let words = words_before(text, m.index);
+        let (type_, rule_id) = match (nearest(&words, ADDRESS_WORDS), nearest(&words, TX_WORDS)) {
+            (Some(a), Some(t)) if a < t => (ADDRESS_TRUNCATED.type_, ADDRESS_TRUNCATED.rule_id),
+            (Some(_), None) => (ADDRESS_TRUNCATED.type_, ADDRESS_TRUNCATED.rule_id),
+            (_, Some(_)) => (TX_TRUNCATED.type_, TX_TRUNCATED.rule_id),
+            (None, None) => {
+                let types: Vec<&str> = candidates.iter().map(|c| c.type_).collect();
+                m.metadata.insert("candidates".to_string(), types.join(","));
+                AMBIGUOUS_TRUNCATED
+            }
+        };
+        m.type_ = type_.to_string();
+        m.rule_id = rule_id.to_string();
+    }
+}
+
+// --- EIP-55 Checksums ---
+
+/// Applies EIP-55 mixed-case checksum casing to a `0x` + 40-hex address.
+pub fn to_checksum_address(address: &str) -> String {
+    let hex = address[2..].to_ascii_lowercase();
+    let mut hash = [0u8; 32];
+    let mut keccak = Keccak::v256();
+    keccak.update(hex.as_bytes());
+    keccak.finalize(&mut hash);
+
+    let body: String = hex
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            let nibble = (hash[i / 2] >> if i % 2 == 0 { 4 } else { 0 }) & 0x0f;
+            if nibble >= 8 {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect();
+    format!("0x{body}")
+}
+
+/// Whether a mixed-case address carries a valid EIP-55 checksum. Single-case
+/// addresses carry no checksum and return `false`.
+pub fn is_eip55_checksummed(address: &str) -> bool {
+    let hex = &address[2..];
+    let mixed =
+        hex.bytes().any(|b| b.is_ascii_uppercase()) && hex.bytes().any(|b| b.is_ascii_lowercase());
+    mixed && to_checksum_address(address) == address
+}
+
+/// Whether a complete `0x` hex value is cased the way real ones are: all
+/// lowercase, all uppercase, or a 40-hex address with a valid EIP-55
+/// checksum. Truncated values and anything that is not pure hex pass, since
+/// their casing cannot be checked.
+fn has_consistent_case(value: &str) -> bool {
+    let Some(hex) = value
+        .strip_prefix("0x")
+        .filter(|h| h.bytes().all(|b| b.is_ascii_hexdigit()))
+    else {
+        return true;
+    };
+    let upper = hex.bytes().any(|b| b.is_ascii_uppercase());
+    let lower = hex.bytes().any(|b| b.is_ascii_lowercase());
+    !(upper && lower) || (hex.len() == 40 && is_eip55_checksummed(value))
+}
+
+/// Drops hex values in random mixed case, e.g. identifiers in minified JS
+/// that happen to be `0x` followed by 40 or 64 hex-looking characters.
+pub(crate) fn require_consistent_case(matches: &mut Vec<Match>) {
+    matches.retain(|m| has_consistent_case(&m.value));
+}
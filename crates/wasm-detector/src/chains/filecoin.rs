@@ -0,0 +1,102 @@
+//! Filecoin addresses: a network prefix (`f` mainnet, `t` testnet), a
+//! protocol digit, and a payload. ID addresses (`f0`) are decimal; the
+//! others are lowercase base32 of the payload plus a 4-byte Blake2b
+//! checksum over the protocol byte and payload. Delegated addresses
+//! (`f4`) carry a decimal actor namespace before a second `f`.
+
+use crate::chains::base32;
+use crate::{Match, Pattern};
+use blake2::Blake2bVar;
+use blake2::digest::{Update, VariableOutput};
+use regex::Regex;
+use std::sync::OnceLock;
+
+const FILECOIN_SRC: &str =
+    r"\b[ft](?:0[0-9]{1,20}|[12][a-z2-7]{39}|3[a-z2-7]{84}|4[0-9]{1,5}f[a-z2-7]{8,90})\b";
+
+const CHECKSUM_LEN: usize = 4;
+
+static FILECOIN_RE: OnceLock<Regex> = OnceLock::new();
+
+fn get_filecoin_re() -> &'static Regex {
+    FILECOIN_RE.get_or_init(|| Regex::new(FILECOIN_SRC).unwrap())
+}
+
+fn checksum(data: &[u8]) -> [u8; CHECKSUM_LEN] {
+    let mut hasher = Blake2bVar::new(CHECKSUM_LEN).unwrap();
+    hasher.update(data);
+    let mut out = [0; CHECKSUM_LEN];
+    hasher.finalize_variable(&mut out).unwrap();
+    out
+}
+
+fn leb128(mut n: u64) -> Vec<u8> {
+    let mut out = Vec::new();
+    loop {
+        let byte = (n & 0x7f) as u8;
+        n >>= 7;
+        if n == 0 {
+            out.push(byte);
+            return out;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+/// Decodes base32 `encoded` as payload plus checksum and verifies the
+/// checksum over `header` (protocol byte, and namespace for `f4`) and the
+/// payload. `payload_len` of `None` accepts any non-empty length.
+fn verify(header: &[u8], encoded: &str, payload_len: Option<usize>) -> bool {
+    let Some(bytes) = base32::decode(&encoded.to_ascii_uppercase()) else {
+        return false;
+    };
+    let Some(split) = bytes.len().checked_sub(CHECKSUM_LEN).filter(|&n| n > 0) else {
+        return false;
+    };
+    let (payload, sum) = bytes.split_at(split);
+    if payload_len.is_some_and(|len| len != payload.len()) {
+        return false;
+    }
+    checksum(&[header, payload].concat()) == sum
+}
+
+fn is_valid_filecoin(value: &str) -> bool {
+    let body = &value[2..];
+    match value.as_bytes()[1] {
+        b'0' => body.parse::<u64>().is_ok(),
+        b'1' => verify(&[1], body, Some(20)),
+        b'2' => verify(&[2], body, Some(20)),
+        b'3' => verify(&[3], body, Some(48)),
+        b'4' => {
+            let Some((namespace, encoded)) = body.split_once('f') else {
+                return false;
+            };
+            let Ok(namespace) = namespace.parse::<u64>() else {
+                return false;
+            };
+            verify(&[&[4][..], &leb128(namespace)].concat(), encoded, None)
+        }
+        _ => false,
+    }
+}
+
+/// Records `network` (`mainnet` or `testnet`) from the address prefix.
+pub(crate) fn describe_networks(matches: &mut [Match]) {
+    for m in matches.iter_mut().filter(|m| m.rule_id == ADDRESS.rule_id) {
+        let network = if m.value.starts_with('f') {
+            "mainnet"
+        } else {
+            "testnet"
+        };
+        m.network = Some(network.to_string());
+    }
+}
+
+pub(crate) const ADDRESS: Pattern = Pattern {
+    type_: "fil_address",
+    rule_id: "filecoin.address",
+    chain: "filecoin",
+    source: FILECOIN_SRC,
+    regex: get_filecoin_re,
+    validate: Some(is_valid_filecoin),
+};
@@ -0,0 +1,49 @@
+//! Flow addresses: `0x` + 16 hex digits, between the shortest EVM value and
+//! the 40-hex address. The same shape is everywhere in other text (64-bit
+//! pointers in stack traces, database ids), so `gate_addresses` keeps a
+//! value only when a Flow word sits nearby.
+
+use crate::{Match, Pattern, grapheme};
+use regex::Regex;
+use std::sync::OnceLock;
+
+const ADDRESS_SRC: &str = r"\b0x[0-9a-fA-F]{16}\b";
+
+/// Grapheme clusters on either side of an address searched for context words.
+const CONTEXT_WINDOW: usize = 48;
+
+const CONTEXT_WORDS: &[&str] = &[
+    "flow", "flowscan", "cadence", "fcl", "dapper", "blocto", "lilico",
+];
+
+static ADDRESS_RE: OnceLock<Regex> = OnceLock::new();
+
+fn get_address_re() -> &'static Regex {
+    ADDRESS_RE.get_or_init(|| Regex::new(ADDRESS_SRC).unwrap())
+}
+
+fn has_context(text: &str, m: &Match) -> bool {
+    let end = m.index + m.value.len();
+    let window = [
+        grapheme::before(text, m.index, CONTEXT_WINDOW),
+        grapheme::after(text, end, CONTEXT_WINDOW),
+    ];
+    window.iter().any(|side| {
+        side.split(|c: char| !c.is_ascii_alphanumeric())
+            .any(|w| CONTEXT_WORDS.contains(&w.to_ascii_lowercase().as_str()))
+    })
+}
+
+/// Drops addresses with no Flow word nearby.
+pub(crate) fn gate_addresses(text: &str, matches: &mut Vec<Match>) {
+    matches.retain(|m| m.rule_id != ADDRESS.rule_id || has_context(text, m));
+}
+
+pub(crate) const ADDRESS: Pattern = Pattern {
+    type_: "flow_address",
+    rule_id: "flow.address",
+    chain: "flow",
+    source: ADDRESS_SRC,
+    regex: get_address_re,
+    validate: None,
+};
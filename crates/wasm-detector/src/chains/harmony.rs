@@ -0,0 +1,48 @@
+//! Harmony addresses: `one1…`, the bech32 encoding (HRP `one`) of a 20-byte
+//! account that is also an EVM address. With `DetectorOptions::normalize`,
+//! each match carries that `0x` form, EIP-55 checksummed.
+
+use crate::bech32;
+use crate::chains::evm::to_checksum_address;
+use crate::{Match, Pattern};
+use regex::Regex;
+use std::sync::OnceLock;
+
+const ADDRESS_SRC: &str = r"(?i)\bone1[qpzry9x8gf2tvdw0s3jn54khce6mua7l]{38}\b";
+
+static ADDRESS_RE: OnceLock<Regex> = OnceLock::new();
+
+fn get_address_re() -> &'static Regex {
+    ADDRESS_RE.get_or_init(|| Regex::new(ADDRESS_SRC).unwrap())
+}
+
+/// The 20 account bytes, if the value is a valid Harmony address.
+fn account(value: &str) -> Option<Vec<u8>> {
+    let decoded =
+        bech32::decode(value).filter(|d| d.variant == bech32::Variant::Bech32 && d.hrp == "one")?;
+    bech32::to_bytes(&decoded.data).filter(|bytes| bytes.len() == 20)
+}
+
+fn is_valid_address(value: &str) -> bool {
+    account(value).is_some()
+}
+
+/// Sets `normalized` to the checksummed `0x` address on every match.
+pub(crate) fn normalize(matches: &mut [Match]) {
+    for m in matches.iter_mut().filter(|m| m.rule_id == ADDRESS.rule_id) {
+        let Some(bytes) = account(&m.value) else {
+            continue;
+        };
+        let hex: String = bytes.iter().map(|b| format!("{b:02x}")).collect();
+        m.normalized = Some(to_checksum_address(&format!("0x{hex}")));
+    }
+}
+
+pub(crate) const ADDRESS: Pattern = Pattern {
+    type_: "one_address",
+    rule_id: "harmony.address",
+    chain: "harmony",
+    source: ADDRESS_SRC,
+    regex: get_address_re,
+    validate: Some(is_valid_address),
+};
@@ -0,0 +1,127 @@
+//! Hedera account ids: `shard.realm.num` (`0.0.123456`), optionally with a
+//! HIP-15 checksum suffix (`0.0.123-vfmkw`). Bare dotted triples look just
+//! like version numbers, so `gate_accounts` keeps them only when a Hedera
+//! word sits nearby; a valid checksum is evidence enough on its own.
+
+use crate::{Match, Pattern, grapheme};
+use regex::Regex;
+use std::sync::OnceLock;
+
+const ACCOUNT_SRC: &str =
+    r"\b(?:0|[1-9][0-9]{0,4})\.(?:0|[1-9][0-9]{0,4})\.(?:0|[1-9][0-9]{0,18})(?:-[a-z]{5})?\b";
+
+/// Grapheme clusters on either side of an id searched for context words.
+const CONTEXT_WINDOW: usize = 48;
+
+const CONTEXT_WORDS: &[&str] = &["hedera", "hbar", "hbars", "account", "acct"];
+
+/// Ledger ids of the public networks, as used in the HIP-15 checksum.
+const LEDGERS: &[(u8, &str)] = &[(0, "mainnet"), (1, "testnet"), (2, "previewnet")];
+
+static ACCOUNT_RE: OnceLock<Regex> = OnceLock::new();
+
+fn get_account_re() -> &'static Regex {
+    ACCOUNT_RE.get_or_init(|| Regex::new(ACCOUNT_SRC).unwrap())
+}
+
+/// HIP-15 checksum of `shard.realm.num` on the ledger with the given id.
+fn checksum(id: &str, ledger: u8) -> String {
+    const P3: u64 = 26 * 26 * 26;
+    const P5: u64 = P3 * 26 * 26;
+    const W: u64 = 31;
+    const M: u64 = 1_000_003;
+
+    let digits: Vec<u64> = id
+        .bytes()
+        .map(|b| if b == b'.' { 10 } else { (b - b'0') as u64 })
+        .collect();
+    let (mut sd0, mut sd1, mut sd) = (0, 0, 0);
+    for (i, &d) in digits.iter().enumerate() {
+        sd = (W * sd + d) % P3;
+        if i % 2 == 0 {
+            sd0 = (sd0 + d) % 11;
+        } else {
+            sd1 = (sd1 + d) % 11;
+        }
+    }
+    // The ledger id is hashed followed by six zero bytes.
+    let sh = [ledger, 0, 0, 0, 0, 0, 0]
+        .iter()
+        .fold(0, |sh, &b| (W * sh + b as u64) % P5);
+    let c = ((((digits.len() as u64 % 5) * 11 + sd0) * 11 + sd1) * P3 + sd + sh) % P5;
+
+    let mut cp = c * M % P5;
+    let mut letters = [0u8; 5];
+    for letter in letters.iter_mut().rev() {
+        *letter = b'a' + (cp % 26) as u8;
+        cp /= 26;
+    }
+    String::from_utf8(letters.to_vec()).unwrap()
+}
+
+/// The network whose checksum the suffix carries, if any.
+fn checksum_network(value: &str) -> Option<&'static str> {
+    let (id, suffix) = value.split_once('-')?;
+    LEDGERS
+        .iter()
+        .find(|(ledger, _)| checksum(id, *ledger) == suffix)
+        .map(|(_, network)| *network)
+}
+
+/// Ids fit in 64 bits; a checksum suffix must verify on some public network.
+fn is_valid_account(value: &str) -> bool {
+    let id = value.split('-').next().unwrap_or_default();
+    let parts_fit = id.split('.').all(|part| part.parse::<u64>().is_ok());
+    parts_fit && (!value.contains('-') || checksum_network(value).is_some())
+}
+
+fn has_context(text: &str, m: &Match) -> bool {
+    let end = m.index + m.value.len();
+    let window = [
+        grapheme::before(text, m.index, CONTEXT_WINDOW),
+        grapheme::after(text, end, CONTEXT_WINDOW),
+    ];
+    window.iter().any(|side| {
+        side.split(|c: char| !c.is_ascii_alphanumeric())
+            .any(|w| CONTEXT_WORDS.contains(&w.to_ascii_lowercase().as_str()))
+    })
+}
+
+/// Whether the id is a slice of a longer dotted number, e.g. the tail of
+/// `10.0.0.12` or the head of `1.2.3.4`.
+fn is_fragment(text: &str, m: &Match) -> bool {
+    let end = m.index + m.value.len();
+    let before = &text.as_bytes()[..m.index];
+    let after = &text.as_bytes()[end..];
+    before.ends_with(b".") && before.len() >= 2 && before[before.len() - 2].is_ascii_digit()
+        || after.first() == Some(&b'.') && after.get(1).is_some_and(u8::is_ascii_digit)
+}
+
+/// Drops ids with neither a valid checksum nor a nearby Hedera word, and
+/// records the checksum's `network` on the ones that carry it.
+pub(crate) fn gate_accounts(text: &str, matches: &mut Vec<Match>) {
+    matches.retain_mut(|m| {
+        if m.rule_id != ACCOUNT.rule_id {
+            return true;
+        }
+        if is_fragment(text, m) {
+            return false;
+        }
+        match checksum_network(&m.value) {
+            Some(network) => {
+                m.network = Some(network.to_string());
+                true
+            }
+            None => has_context(text, m),
+        }
+    });
+}
+
+pub(crate) const ACCOUNT: Pattern = Pattern {
+    type_: "hbar_account",
+    rule_id: "hedera.account",
+    chain: "hedera",
+    source: ACCOUNT_SRC,
+    regex: get_account_re,
+    validate: Some(is_valid_account),
+};
@@ -0,0 +1,116 @@
+//! Internet Computer principals and ledger account identifiers. Principals
+//! are lowercase base32 of a CRC32 followed by the principal's bytes,
+//! grouped in fives with dashes (`ryjl3-tyaaa-aaaaa-aaaba-cai`). Account
+//! identifiers are 64 hex digits, a CRC32 followed by a 28-byte hash; they
+//! share the shape of NEAR implicit accounts and other 64-hex values, so
+//! `gate_accounts` keeps them only when an ICP word sits nearby.
+
+use crate::chains::base32;
+use crate::{DetectorOptions, Match, Pattern, grapheme};
+use regex::Regex;
+use std::sync::OnceLock;
+
+// Three to eleven groups: canister ids have five, self-authenticating
+// principals eleven. The two-group well-known ids are not personal data.
+const PRINCIPAL_SRC: &str = r"\b[a-z2-7]{5}(?:-[a-z2-7]{5}){1,9}-[a-z2-7]{1,5}\b";
+const ACCOUNT_SRC: &str = r"\b[0-9a-f]{64}\b";
+
+/// Principals are at most 29 bytes.
+const MAX_PRINCIPAL_LEN: usize = 29;
+
+/// Grapheme clusters on either side of an account id searched for context words.
+const CONTEXT_WINDOW: usize = 48;
+
+const CONTEXT_WORDS: &[&str] = &["icp", "dfinity", "nns", "plug", "stoic"];
+
+static PRINCIPAL_RE: OnceLock<Regex> = OnceLock::new();
+static ACCOUNT_RE: OnceLock<Regex> = OnceLock::new();
+
+fn get_principal_re() -> &'static Regex {
+    PRINCIPAL_RE.get_or_init(|| Regex::new(PRINCIPAL_SRC).unwrap())
+}
+
+fn get_account_re() -> &'static Regex {
+    ACCOUNT_RE.get_or_init(|| Regex::new(ACCOUNT_SRC).unwrap())
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    !data.iter().fold(!0u32, |crc, &b| {
+        (0..8).fold(crc ^ b as u32, |crc, _| {
+            if crc & 1 != 0 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            }
+        })
+    })
+}
+
+/// Whether `bytes` is a big-endian CRC32 followed by the data it covers.
+fn has_crc_prefix(bytes: &[u8]) -> bool {
+    bytes.len() >= 4 && crc32(&bytes[4..]).to_be_bytes() == bytes[..4]
+}
+
+fn is_valid_principal(value: &str) -> bool {
+    let encoded = value.replace('-', "").to_ascii_uppercase();
+    base32::decode(&encoded)
+        .is_some_and(|bytes| bytes.len() <= 4 + MAX_PRINCIPAL_LEN && has_crc_prefix(&bytes))
+}
+
+fn is_valid_account(value: &str) -> bool {
+    let bytes: Vec<u8> = (0..value.len())
+        .step_by(2)
+        .filter_map(|i| u8::from_str_radix(&value[i..i + 2], 16).ok())
+        .collect();
+    bytes.len() == 32 && has_crc_prefix(&bytes)
+}
+
+fn has_context(text: &str, m: &Match) -> bool {
+    let end = m.index + m.value.len();
+    let window = [
+        grapheme::before(text, m.index, CONTEXT_WINDOW),
+        grapheme::after(text, end, CONTEXT_WINDOW),
+    ];
+    window.iter().any(|side| {
+        side.split(|c: char| !c.is_ascii_alphanumeric())
+            .any(|w| CONTEXT_WORDS.contains(&w.to_ascii_lowercase().as_str()))
+    })
+}
+
+/// Keeps account ids only with an ICP word nearby, and relabels NEAR
+/// implicit accounts (which claim the same values first) that carry a valid
+/// CRC and such a word.
+pub(crate) fn gate_accounts(text: &str, matches: &mut Vec<Match>, options: &DetectorOptions) {
+    let enabled = !options.disabled.iter().any(|t| t == ACCOUNT.type_);
+    matches.retain_mut(|m| {
+        let own = m.rule_id == ACCOUNT.rule_id;
+        let near = m.rule_id == "near.implicit_account" && enabled && is_valid_account(&m.value);
+        if !own && !near {
+            return true;
+        }
+        let is_icp = has_context(text, m);
+        if is_icp {
+            m.type_ = ACCOUNT.type_.to_string();
+            m.rule_id = ACCOUNT.rule_id.to_string();
+        }
+        is_icp || near
+    });
+}
+
+pub(crate) const PRINCIPAL: Pattern = Pattern {
+    type_: "icp_principal",
+    rule_id: "icp.principal",
+    chain: "icp",
+    source: PRINCIPAL_SRC,
+    regex: get_principal_re,
+    validate: Some(is_valid_principal),
+};
+
+pub(crate) const ACCOUNT: Pattern = Pattern {
+    type_: "icp_account",
+    rule_id: "icp.account",
+    chain: "icp",
+    source: ACCOUNT_SRC,
+    regex: get_account_re,
+    validate: Some(is_valid_account),
+};
@@ -0,0 +1,99 @@
+//! Kaspa addresses: `kaspa:` (or `kaspatest:`, `kaspadev:`, `kaspasim:`)
+//! followed by a cashaddr payload whose version byte selects a Schnorr key
+//! (`q`, 32 bytes), an ECDSA key (`q`, 33 bytes) or a script hash (`p`, 32
+//! bytes). Bare payloads are checked against the mainnet prefix and kept
+//! only when `gate_addresses` finds a Kaspa word nearby.
+
+use crate::chains::cashaddr;
+use crate::{Match, Pattern, grapheme};
+use regex::Regex;
+use std::sync::OnceLock;
+
+const ADDRESS_SRC: &str = r"(?i)\b(?:kaspa(?:test|dev|sim)?:)?[qp][qpzry9x8gf2tvdw0s3jn54khce6mua7l]{60}(?:[qpzry9x8gf2tvdw0s3jn54khce6mua7l]{2})?\b";
+
+const DEFAULT_PREFIX: &str = "kaspa";
+
+/// Address prefixes and the network each one names.
+const NETWORKS: &[(&str, &str)] = &[
+    ("kaspa", "mainnet"),
+    ("kaspatest", "testnet"),
+    ("kaspadev", "devnet"),
+    ("kaspasim", "simnet"),
+];
+
+/// Version bytes and the payload length (in characters) each one implies.
+const VERSIONS: &[(u8, usize)] = &[(0, 61), (1, 63), (8, 61)];
+
+/// Grapheme clusters on either side of a bare payload searched for context.
+const CONTEXT_WINDOW: usize = 48;
+const CONTEXT_WORDS: &[&str] = &["kaspa", "kas"];
+
+static ADDRESS_RE: OnceLock<Regex> = OnceLock::new();
+
+fn get_address_re() -> &'static Regex {
+    ADDRESS_RE.get_or_init(|| Regex::new(ADDRESS_SRC).unwrap())
+}
+
+fn split_prefix(value: &str) -> (String, String) {
+    let value = value.to_ascii_lowercase();
+    match value.split_once(':') {
+        Some((prefix, payload)) => (prefix.to_string(), payload.to_string()),
+        None => (DEFAULT_PREFIX.to_string(), value),
+    }
+}
+
+/// Verifies the checksum and that the version byte matches the payload length.
+fn is_valid_address(value: &str) -> bool {
+    let has_lower = value.bytes().any(|b| b.is_ascii_lowercase());
+    let has_upper = value.bytes().any(|b| b.is_ascii_uppercase());
+    if has_lower && has_upper {
+        return false;
+    }
+    let (prefix, payload) = split_prefix(value);
+    let Some(words) = cashaddr::decode(&prefix, &payload) else {
+        return false;
+    };
+    // The version byte spans the first word and the top three bits of the second.
+    let version = (words[0] << 3) | (words[1] >> 2);
+    VERSIONS.contains(&(version, payload.len()))
+}
+
+fn has_context(text: &str, m: &Match) -> bool {
+    let end = m.index + m.value.len();
+    [
+        grapheme::before(text, m.index, CONTEXT_WINDOW),
+        grapheme::after(text, end, CONTEXT_WINDOW),
+    ]
+    .iter()
+    .any(|side| {
+        side.split(|c: char| !c.is_ascii_alphanumeric())
+            .any(|w| CONTEXT_WORDS.contains(&w.to_ascii_lowercase().as_str()))
+    })
+}
+
+/// Drops bare payloads with no Kaspa word nearby and records each kept
+/// address's `network` from its prefix.
+pub(crate) fn gate_addresses(text: &str, matches: &mut Vec<Match>) {
+    matches.retain_mut(|m| {
+        if m.rule_id != ADDRESS.rule_id {
+            return true;
+        }
+        if !m.value.contains(':') && !has_context(text, m) {
+            return false;
+        }
+        let (prefix, _) = split_prefix(&m.value);
+        if let Some((_, network)) = NETWORKS.iter().find(|(p, _)| *p == prefix) {
+            m.network = Some(network.to_string());
+        }
+        true
+    });
+}
+
+pub(crate) const ADDRESS: Pattern = Pattern {
+    type_: "kas_address",
+    rule_id: "kaspa.address",
+    chain: "kaspa",
+    source: ADDRESS_SRC,
+    regex: get_address_re,
+    validate: Some(is_valid_address),
+};
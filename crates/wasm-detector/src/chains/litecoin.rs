@@ -0,0 +1,115 @@
+//! Litecoin mainnet addresses: legacy P2PKH (`L...`) and P2SH (`M...`),
+//! Base58Check-encoded, and SegWit `ltc1...`, bech32-encoded. They sit ahead
+//! of the generic bech32 and Base58 patterns, which would otherwise report
+//! them under a less specific type. Shortened legacy forms carry no
+//! checksum, so `gate_truncated` keeps them as Litecoin only with a
+//! Litecoin word nearby.
+
+use crate::bech32;
+use crate::chains::{base58check, reassign};
+use crate::{Match, Pattern, grapheme};
+use regex::Regex;
+use std::sync::OnceLock;
+
+const LEGACY_SRC: &str = r"\b[LM][1-9A-HJ-NP-Za-km-z]{25,33}\b";
+const LEGACY_TRUNCATED_SRC: &str =
+    r"\b[LM][1-9A-HJ-NP-Za-km-z]{3,10}(?:\.{3}|…)[1-9A-HJ-NP-Za-km-z]{3,10}\b";
+const SEGWIT_SRC: &str = r"(?i)\bltc1[qpzry9x8gf2tvdw0s3jn54khce6mua7l]{38,58}\b";
+const SEGWIT_TRUNCATED_SRC: &str = r"(?i)\bltc1[qpzry9x8gf2tvdw0s3jn54khce6mua7l]{3,10}(?:\.{3}|…)[qpzry9x8gf2tvdw0s3jn54khce6mua7l]{4,10}\b";
+
+/// Grapheme clusters on either side of a shortened address searched for
+/// context words.
+const CONTEXT_WINDOW: usize = 48;
+
+const CONTEXT_WORDS: &[&str] = &["ltc", "litecoin", "litecoinspace", "litewallet"];
+
+/// Version bytes of mainnet P2PKH (`L`) and P2SH (`M`) addresses.
+const LEGACY_VERSIONS: [u8; 2] = [0x30, 0x32];
+
+static LEGACY_RE: OnceLock<Regex> = OnceLock::new();
+static LEGACY_TRUNCATED_RE: OnceLock<Regex> = OnceLock::new();
+static SEGWIT_RE: OnceLock<Regex> = OnceLock::new();
+static SEGWIT_TRUNCATED_RE: OnceLock<Regex> = OnceLock::new();
+
+fn get_legacy_re() -> &'static Regex {
+    LEGACY_RE.get_or_init(|| Regex::new(LEGACY_SRC).unwrap())
+}
+
+fn get_legacy_truncated_re() -> &'static Regex {
+    LEGACY_TRUNCATED_RE.get_or_init(|| Regex::new(LEGACY_TRUNCATED_SRC).unwrap())
+}
+
+fn get_segwit_re() -> &'static Regex {
+    SEGWIT_RE.get_or_init(|| Regex::new(SEGWIT_SRC).unwrap())
+}
+
+fn get_segwit_truncated_re() -> &'static Regex {
+    SEGWIT_TRUNCATED_RE.get_or_init(|| Regex::new(SEGWIT_TRUNCATED_SRC).unwrap())
+}
+
+fn is_valid_legacy(value: &str) -> bool {
+    base58check::decode(value)
+        .is_some_and(|payload| payload.len() == 21 && LEGACY_VERSIONS.contains(&payload[0]))
+}
+
+fn is_valid_segwit(value: &str) -> bool {
+    bech32::decode(value).is_some_and(|d| d.hrp == "ltc")
+}
+
+fn has_context(text: &str, m: &Match) -> bool {
+    let end = m.index + m.value.len();
+    let window = [
+        grapheme::before(text, m.index, CONTEXT_WINDOW),
+        grapheme::after(text, end, CONTEXT_WINDOW),
+    ];
+    window.iter().any(|side| {
+        side.split(|c: char| !c.is_ascii_alphanumeric())
+            .any(|w| CONTEXT_WORDS.contains(&w.to_ascii_lowercase().as_str()))
+    })
+}
+
+/// Passes shortened `L…`/`M…` values with no Litecoin word nearby to the
+/// next pattern that would have claimed them, e.g. a Solana short form.
+pub(crate) fn gate_truncated(text: &str, matches: &mut [Match], patterns: &[&'static Pattern]) {
+    for m in matches.iter_mut() {
+        if m.rule_id == ADDRESS_TRUNCATED.rule_id && !has_context(text, m) {
+            reassign(m, patterns);
+        }
+    }
+}
+
+pub(crate) const ADDRESS: Pattern = Pattern {
+    type_: "ltc_address",
+    rule_id: "litecoin.address",
+    chain: "litecoin",
+    source: LEGACY_SRC,
+    regex: get_legacy_re,
+    validate: Some(is_valid_legacy),
+};
+
+pub(crate) const ADDRESS_TRUNCATED: Pattern = Pattern {
+    type_: "ltc_truncated",
+    rule_id: "litecoin.address.truncated",
+    chain: "litecoin",
+    source: LEGACY_TRUNCATED_SRC,
+    regex: get_legacy_truncated_re,
+    validate: None,
+};
+
+pub(crate) const BECH32_ADDRESS: Pattern = Pattern {
+    type_: "ltc_bech32_address",
+    rule_id: "litecoin.bech32_address",
+    chain: "litecoin",
+    source: SEGWIT_SRC,
+    regex: get_segwit_re,
+    validate: Some(is_valid_segwit),
+};
+
+pub(crate) const BECH32_ADDRESS_TRUNCATED: Pattern = Pattern {
+    type_: "ltc_bech32_truncated",
+    rule_id: "litecoin.bech32_address.truncated",
+    chain: "litecoin",
+    source: SEGWIT_TRUNCATED_SRC,
+    regex: get_segwit_truncated_re,
+    validate: None,
+};
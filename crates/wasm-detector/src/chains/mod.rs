@@ -0,0 +1,321 @@
+//! One module per chain family. Each family's regexes and validators are
+//! compiled only when its cargo feature is enabled, so hosts can ship a wasm
+//! build containing just the families they need.
+
+#[cfg(any(feature = "xrp", feature = "litecoin"))]
+use crate::Match;
+use crate::Pattern;
+
+#[cfg(feature = "algorand")]
+mod algorand;
+#[cfg(feature = "avalanche")]
+pub(crate) mod avalanche;
+#[cfg(any(
+    feature = "stellar",
+    feature = "algorand",
+    feature = "filecoin",
+    feature = "icp"
+))]
+mod base32;
+#[cfg(any(
+    feature = "bitcoin",
+    feature = "tron",
+    feature = "xrp",
+    feature = "litecoin",
+    feature = "dogecoin",
+    feature = "zcash"
+))]
+mod base58check;
+#[cfg(feature = "bech32")]
+pub mod bech32;
+#[cfg(feature = "bitcoin")]
+pub(crate) mod bitcoin;
+#[cfg(feature = "bitcoincash")]
+pub(crate) mod bitcoincash;
+#[cfg(feature = "cardano")]
+mod cardano;
+#[cfg(any(feature = "bitcoincash", feature = "kaspa"))]
+mod cashaddr;
+#[cfg(feature = "chia")]
+pub(crate) mod chia;
+#[cfg(feature = "deeplinks")]
+pub(crate) mod deeplink;
+#[cfg(feature = "dogecoin")]
+mod dogecoin;
+#[cfg(feature = "ens")]
+pub(crate) mod ens;
+#[cfg(feature = "eos")]
+pub(crate) mod eos;
+#[cfg(feature = "evm")]
+pub mod evm;
+#[cfg(feature = "filecoin")]
+pub(crate) mod filecoin;
+#[cfg(feature = "flow")]
+pub(crate) mod flow;
+#[cfg(feature = "harmony")]
+pub(crate) mod harmony;
+#[cfg(feature = "hedera")]
+pub(crate) mod hedera;
+#[cfg(feature = "icp")]
+pub(crate) mod icp;
+#[cfg(feature = "kaspa")]
+pub(crate) mod kaspa;
+#[cfg(feature = "litecoin")]
+pub(crate) mod litecoin;
+#[cfg(feature = "multiversx")]
+mod multiversx;
+#[cfg(feature = "nano")]
+mod nano;
+#[cfg(feature = "near")]
+pub(crate) mod near;
+#[cfg(feature = "sns")]
+pub(crate) mod sns;
+#[cfg(feature = "solana")]
+mod solana;
+#[cfg(feature = "ss58")]
+mod ss58;
+#[cfg(feature = "stacks")]
+pub(crate) mod stacks;
+#[cfg(feature = "starknet")]
+pub(crate) mod starknet;
+#[cfg(feature = "stellar")]
+mod stellar;
+#[cfg(feature = "tron")]
+mod tron;
+#[cfg(feature = "unstoppable")]
+mod unstoppable;
+#[cfg(feature = "xrp")]
+pub(crate) mod xrp;
+#[cfg(feature = "zcash")]
+mod zcash;
+
+/// Every pattern in global priority order. Deep links come first since they
+/// contain other values, then families with a fixed literal prefix (which
+/// the generic Base58 patterns would otherwise claim), then transaction
+/// hashes (longer patterns before shorter ones), then addresses, so a span
+/// claimed by an earlier entry is never re-reported by a later one.
+pub static PATTERNS: &[Pattern] = &[
+    #[cfg(feature = "deeplinks")]
+    deeplink::WALLET_LINK,
+    #[cfg(feature = "avalanche")]
+    avalanche::ADDRESS,
+    #[cfg(feature = "chia")]
+    chia::ADDRESS,
+    #[cfg(feature = "harmony")]
+    harmony::ADDRESS,
+    #[cfg(feature = "multiversx")]
+    multiversx::ADDRESS,
+    #[cfg(feature = "cardano")]
+    cardano::ADDRESS,
+    #[cfg(feature = "cardano")]
+    cardano::STAKE_ADDRESS,
+    #[cfg(feature = "cardano")]
+    cardano::ADDRESS_TRUNCATED,
+    #[cfg(feature = "cardano")]
+    cardano::STAKE_ADDRESS_TRUNCATED,
+    #[cfg(feature = "bitcoin")]
+    bitcoin::SEGWIT_ADDRESS,
+    #[cfg(feature = "bitcoin")]
+    bitcoin::TAPROOT_ADDRESS,
+    #[cfg(feature = "bitcoin")]
+    bitcoin::LEGACY_ADDRESS,
+    #[cfg(feature = "bitcoin")]
+    bitcoin::INSCRIPTION,
+    #[cfg(feature = "litecoin")]
+    litecoin::BECH32_ADDRESS,
+    #[cfg(feature = "litecoin")]
+    litecoin::BECH32_ADDRESS_TRUNCATED,
+    #[cfg(feature = "litecoin")]
+    litecoin::ADDRESS,
+    #[cfg(feature = "litecoin")]
+    litecoin::ADDRESS_TRUNCATED,
+    #[cfg(feature = "dogecoin")]
+    dogecoin::ADDRESS,
+    #[cfg(feature = "zcash")]
+    zcash::TRANSPARENT_ADDRESS,
+    #[cfg(feature = "zcash")]
+    zcash::SHIELDED_ADDRESS,
+    #[cfg(feature = "zcash")]
+    zcash::UNIFIED_ADDRESS,
+    #[cfg(feature = "tron")]
+    tron::ADDRESS,
+    #[cfg(feature = "stacks")]
+    stacks::ADDRESS,
+    #[cfg(feature = "bitcoincash")]
+    bitcoincash::ADDRESS,
+    #[cfg(feature = "bitcoincash")]
+    bitcoincash::SCRIPT_ADDRESS,
+    #[cfg(feature = "kaspa")]
+    kaspa::ADDRESS,
+    #[cfg(feature = "stellar")]
+    stellar::ADDRESS,
+    #[cfg(feature = "stellar")]
+    stellar::ADDRESS_TRUNCATED,
+    #[cfg(feature = "algorand")]
+    algorand::ADDRESS,
+    #[cfg(feature = "filecoin")]
+    filecoin::ADDRESS,
+    #[cfg(feature = "nano")]
+    nano::ADDRESS,
+    #[cfg(feature = "icp")]
+    icp::PRINCIPAL,
+    #[cfg(feature = "xrp")]
+    xrp::ADDRESS,
+    #[cfg(feature = "xrp")]
+    xrp::X_ADDRESS,
+    #[cfg(feature = "xrp")]
+    xrp::ADDRESS_TRUNCATED,
+    #[cfg(feature = "xrp")]
+    xrp::X_ADDRESS_TRUNCATED,
+    #[cfg(feature = "bech32")]
+    bech32::ADDRESS_TRUNCATED,
+    #[cfg(feature = "evm")]
+    evm::TX_HASH,
+    #[cfg(feature = "evm")]
+    evm::TX_TRUNCATED,
+    #[cfg(feature = "solana")]
+    solana::TX_SIGNATURE,
+    #[cfg(feature = "solana")]
+    solana::TX_TRUNCATED,
+    #[cfg(feature = "evm")]
+    evm::ADDRESS,
+    #[cfg(feature = "evm")]
+    evm::ADDRESS_TRUNCATED,
+    #[cfg(feature = "starknet")]
+    starknet::ADDRESS,
+    #[cfg(feature = "flow")]
+    flow::ADDRESS,
+    #[cfg(feature = "bech32")]
+    bech32::ADDRESS,
+    #[cfg(feature = "ss58")]
+    ss58::POLKADOT_ADDRESS,
+    #[cfg(feature = "ss58")]
+    ss58::KUSAMA_ADDRESS,
+    #[cfg(feature = "ss58")]
+    ss58::SUBSTRATE_ADDRESS,
+    #[cfg(feature = "near")]
+    near::IMPLICIT_ACCOUNT,
+    #[cfg(feature = "icp")]
+    icp::ACCOUNT,
+    #[cfg(feature = "solana")]
+    solana::ADDRESS,
+    #[cfg(feature = "solana")]
+    solana::ADDRESS_TRUNCATED,
+    #[cfg(feature = "ens")]
+    ens::NAME,
+    #[cfg(feature = "unstoppable")]
+    unstoppable::NAME,
+    #[cfg(feature = "sns")]
+    sns::NAME,
+    #[cfg(feature = "near")]
+    near::ACCOUNT,
+    #[cfg(feature = "hedera")]
+    hedera::ACCOUNT,
+    #[cfg(feature = "eos")]
+    eos::ACCOUNT,
+    #[cfg(feature = "evm")]
+    evm::CHAIN_ID,
+];
+
+/// Hands a match its context gate rejected to the first pattern after its
+/// own in `patterns` that matches the whole value: the one the scan would
+/// have reported without the gated pattern. The match is left as it is
+/// when no other pattern claims the value.
+#[cfg(any(feature = "xrp", feature = "litecoin"))]
+pub(crate) fn reassign(m: &mut Match, patterns: &[&'static Pattern]) {
+    let Some(own) = patterns.iter().position(|p| p.rule_id == m.rule_id) else {
+        return;
+    };
+    let whole = |p: &&&Pattern| {
+        p.regex()
+            .find(&m.value)
+            .is_some_and(|f| f.range() == (0..m.value.len()))
+            && p.accepts(&m.value)
+    };
+    if let Some(next) = patterns[own + 1..].iter().find(whole) {
+        m.type_ = next.type_.to_string();
+        m.rule_id = next.rule_id.to_string();
+    }
+}
+
+/// Whether the pattern's kind of value is part of this build. The coarse
+/// `names`, `addresses` and `transactions` features each bundle the families
+/// for one kind and, once any of them is enabled, limit mixed families (EVM
+/// and Solana report both addresses and transactions) to the chosen kinds.
+pub(crate) fn kind_compiled(pattern: &Pattern) -> bool {
+    let selected = cfg!(any(
+        feature = "names",
+        feature = "addresses",
+        feature = "transactions"
+    ));
+    if !selected {
+        return true;
+    }
+    let kind = if pattern.rule_id.starts_with("ens.")
+        || pattern.rule_id.starts_with("unstoppable.")
+        || pattern.rule_id.starts_with("sns.")
+        || pattern.rule_id == "near.account"
+    {
+        "names"
+    } else if pattern.rule_id.contains(".tx_") {
+        "transactions"
+    } else {
+        "addresses"
+    };
+    [
+        ("names", cfg!(feature = "names")),
+        ("addresses", cfg!(feature = "addresses")),
+        ("transactions", cfg!(feature = "transactions")),
+    ]
+    .contains(&(kind, true))
+}
+
+/// Cargo features this build was compiled with.
+pub fn enabled_features() -> Vec<&'static str> {
+    [
+        ("evm", cfg!(feature = "evm")),
+        ("solana", cfg!(feature = "solana")),
+        ("ens", cfg!(feature = "ens")),
+        ("unstoppable", cfg!(feature = "unstoppable")),
+        ("sns", cfg!(feature = "sns")),
+        ("ss58", cfg!(feature = "ss58")),
+        ("tron", cfg!(feature = "tron")),
+        ("xrp", cfg!(feature = "xrp")),
+        ("litecoin", cfg!(feature = "litecoin")),
+        ("dogecoin", cfg!(feature = "dogecoin")),
+        ("zcash", cfg!(feature = "zcash")),
+        ("near", cfg!(feature = "near")),
+        ("stellar", cfg!(feature = "stellar")),
+        ("algorand", cfg!(feature = "algorand")),
+        ("filecoin", cfg!(feature = "filecoin")),
+        ("hedera", cfg!(feature = "hedera")),
+        ("bitcoincash", cfg!(feature = "bitcoincash")),
+        ("avalanche", cfg!(feature = "avalanche")),
+        ("bitcoin", cfg!(feature = "bitcoin")),
+        ("nano", cfg!(feature = "nano")),
+        ("icp", cfg!(feature = "icp")),
+        ("kaspa", cfg!(feature = "kaspa")),
+        ("chia", cfg!(feature = "chia")),
+        ("harmony", cfg!(feature = "harmony")),
+        ("eos", cfg!(feature = "eos")),
+        ("multiversx", cfg!(feature = "multiversx")),
+        ("stacks", cfg!(feature = "stacks")),
+        ("flow", cfg!(feature = "flow")),
+        ("starknet", cfg!(feature = "starknet")),
+        ("bech32", cfg!(feature = "bech32")),
+        ("cardano", cfg!(feature = "cardano")),
+        ("deeplinks", cfg!(feature = "deeplinks")),
+        ("names", cfg!(feature = "names")),
+        ("addresses", cfg!(feature = "addresses")),
+        ("transactions", cfg!(feature = "transactions")),
+        ("async", cfg!(feature = "async")),
+        ("audit", cfg!(feature = "audit")),
+        ("bindgen", cfg!(feature = "bindgen")),
+        ("metrics", cfg!(feature = "metrics")),
+        ("mmap", cfg!(feature = "mmap")),
+        ("wasi", cfg!(feature = "wasi")),
+    ]
+    .into_iter()
+    .filter_map(|(name, enabled)| enabled.then_some(name))
+    .collect()
+}
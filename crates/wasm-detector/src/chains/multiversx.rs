@@ -0,0 +1,31 @@
+//! MultiversX (formerly Elrond) addresses: `erd1…`, the bech32 encoding of
+//! a 32-byte Ed25519 public key or smart-contract address.
+
+use crate::Pattern;
+use crate::bech32;
+use regex::Regex;
+use std::sync::OnceLock;
+
+const ADDRESS_SRC: &str = r"(?i)\berd1[qpzry9x8gf2tvdw0s3jn54khce6mua7l]{58}\b";
+
+static ADDRESS_RE: OnceLock<Regex> = OnceLock::new();
+
+fn get_address_re() -> &'static Regex {
+    ADDRESS_RE.get_or_init(|| Regex::new(ADDRESS_SRC).unwrap())
+}
+
+fn is_valid_address(value: &str) -> bool {
+    bech32::decode(value)
+        .filter(|d| d.variant == bech32::Variant::Bech32 && d.hrp == "erd")
+        .and_then(|d| bech32::to_bytes(&d.data))
+        .is_some_and(|bytes| bytes.len() == 32)
+}
+
+pub(crate) const ADDRESS: Pattern = Pattern {
+    type_: "egld_address",
+    rule_id: "multiversx.address",
+    chain: "multiversx",
+    source: ADDRESS_SRC,
+    regex: get_address_re,
+    validate: Some(is_valid_address),
+};
@@ -0,0 +1,70 @@
+//! Nano addresses: `nano_` (or the legacy `xrb_`) followed by 60 characters
+//! of Nano's own base32 alphabet, encoding 4 zero bits, the 32-byte public
+//! key, and a 5-byte Blake2b checksum of the key in reversed byte order.
+
+use crate::Pattern;
+use blake2::Blake2bVar;
+use blake2::digest::{Update, VariableOutput};
+use regex::Regex;
+use std::sync::OnceLock;
+
+const ALPHABET: &[u8; 32] = b"13456789abcdefghijkmnopqrstuwxyz";
+
+const NANO_SRC: &str = r"\b(?:nano|xrb)_[13][13456789abcdefghijkmnopqrstuwxyz]{59}\b";
+
+const KEY_LEN: usize = 32;
+const CHECKSUM_LEN: usize = 5;
+
+static NANO_RE: OnceLock<Regex> = OnceLock::new();
+
+fn get_nano_re() -> &'static Regex {
+    NANO_RE.get_or_init(|| Regex::new(NANO_SRC).unwrap())
+}
+
+/// Decodes the 60 characters after the prefix into key and checksum bytes.
+/// The first character carries only the lowest of its 5 bits; the 4 above
+/// it are the zero padding.
+fn decode(encoded: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(KEY_LEN + CHECKSUM_LEN);
+    let (mut acc, mut bits) = (0u32, 0u32);
+    for (i, c) in encoded.bytes().enumerate() {
+        let v = ALPHABET.iter().position(|&a| a == c)? as u32;
+        if i == 0 {
+            (acc, bits) = (v, 1);
+            continue;
+        }
+        acc = (acc << 5) | v;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((acc >> bits) as u8);
+            acc &= (1 << bits) - 1;
+        }
+    }
+    Some(bytes)
+}
+
+fn is_valid_nano(value: &str) -> bool {
+    let Some((_, encoded)) = value.split_once('_') else {
+        return false;
+    };
+    let Some(bytes) = decode(encoded).filter(|b| b.len() == KEY_LEN + CHECKSUM_LEN) else {
+        return false;
+    };
+    let (key, sum) = bytes.split_at(KEY_LEN);
+    let mut hasher = Blake2bVar::new(CHECKSUM_LEN).unwrap();
+    hasher.update(key);
+    let mut expected = [0; CHECKSUM_LEN];
+    hasher.finalize_variable(&mut expected).unwrap();
+    expected.reverse();
+    expected == sum
+}
+
+pub(crate) const ADDRESS: Pattern = Pattern {
+    type_: "nano_address",
+    rule_id: "nano.address",
+    chain: "nano",
+    source: NANO_SRC,
+    regex: get_nano_re,
+    validate: Some(is_valid_nano),
+};
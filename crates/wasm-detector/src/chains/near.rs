@@ -0,0 +1,83 @@
+//! NEAR accounts: named accounts under the `.near` registrar (`alice.near`,
+//! `app.alice.near`) and 64-hex implicit accounts. Bare 64-hex values are
+//! just as often sha256 digests, git object ids or Bitcoin txids, so
+//! `gate_implicit_accounts` keeps one only when a NEAR word sits nearby.
+
+use crate::{Match, Pattern, grapheme};
+use regex::Regex;
+use std::sync::OnceLock;
+
+// Trailing labels are matched too and then rejected by the validator, so
+// `alice.near.org` is not reported as `alice.near`.
+const NAMED_SRC: &str = r"\b(?:[a-z0-9]+(?:[-_][a-z0-9]+)*\.)+near(?:\.[a-z0-9-]+)*\b";
+const IMPLICIT_SRC: &str = r"\b[0-9a-f]{64}\b";
+
+/// Grapheme clusters on either side of an implicit account searched for
+/// context words.
+const CONTEXT_WINDOW: usize = 48;
+
+const CONTEXT_WORDS: &[&str] = &[
+    "near",
+    "nearblocks",
+    "nearscan",
+    "implicit",
+    "yoctonear",
+    "meteor",
+    "mynearwallet",
+];
+
+static NAMED_RE: OnceLock<Regex> = OnceLock::new();
+static IMPLICIT_RE: OnceLock<Regex> = OnceLock::new();
+
+fn get_named_re() -> &'static Regex {
+    NAMED_RE.get_or_init(|| Regex::new(NAMED_SRC).unwrap())
+}
+
+fn get_implicit_re() -> &'static Regex {
+    IMPLICIT_RE.get_or_init(|| Regex::new(IMPLICIT_SRC).unwrap())
+}
+
+/// Account ids are at most 64 characters, and accounts directly under
+/// `near` need at least two characters of their own.
+fn is_valid_named(text: &str) -> bool {
+    let Some(name) = text.strip_suffix(".near") else {
+        return false;
+    };
+    let top = name.rsplit('.').next().unwrap_or_default();
+    text.len() <= 64 && top.len() >= 2
+}
+
+fn has_context(text: &str, m: &Match) -> bool {
+    let end = m.index + m.value.len();
+    let window = [
+        grapheme::before(text, m.index, CONTEXT_WINDOW),
+        grapheme::after(text, end, CONTEXT_WINDOW),
+    ];
+    window.iter().any(|side| {
+        side.split(|c: char| !c.is_ascii_alphanumeric())
+            .any(|w| CONTEXT_WORDS.contains(&w.to_ascii_lowercase().as_str()))
+    })
+}
+
+/// Drops implicit accounts with no NEAR word nearby.
+pub(crate) fn gate_implicit_accounts(text: &str, matches: &mut Vec<Match>) {
+    matches.retain(|m| m.rule_id != IMPLICIT_ACCOUNT.rule_id || has_context(text, m));
+}
+
+pub(crate) const ACCOUNT: Pattern = Pattern {
+    type_: "near_account",
+    rule_id: "near.account",
+    chain: "near",
+    source: NAMED_SRC,
+    regex: get_named_re,
+    validate: Some(is_valid_named),
+};
+
+pub(crate) const IMPLICIT_ACCOUNT: Pattern = Pattern {
+    type_: "near_implicit_account",
+    rule_id: "near.implicit_account",
+    chain: "near",
+    source: IMPLICIT_SRC,
+    regex: get_implicit_re,
+    validate: None,
+};
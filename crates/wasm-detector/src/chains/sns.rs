@@ -0,0 +1,93 @@
+//! Solana Name Service names (`bonfida.sol`, `pay.alice.sol`). Solidity
+//! sources share the suffix, so `filter_filenames` drops values that read
+//! as file names (`ERC20.sol`, `contracts/lib.sol`) unless an SNS word sits
+//! nearby; `DetectorOptions::sns_require_context` demands that word for
+//! every name.
+
+use crate::{DetectorOptions, Match, Pattern, grapheme};
+use regex::Regex;
+use std::sync::OnceLock;
+
+// Trailing labels are matched so that `alice.sol.example` can be rejected
+// whole instead of reported as `alice.sol`.
+const NAME_SRC: &str = r"(?i)\b(?:[a-z0-9](?:[a-z0-9_-]{0,61}[a-z0-9])?\.)+sol(?:\.[a-z0-9-]+)*\b";
+
+/// Grapheme clusters on either side of a name searched for context words.
+const CONTEXT_WINDOW: usize = 48;
+
+const CONTEXT_WORDS: &[&str] = &[
+    "sns", "bonfida", "solana", "phantom", "solflare", "domain", "wallet",
+];
+
+/// Words that mark a `.sol` value as a Solidity source file.
+const SOLIDITY_WORDS: &[&str] = &[
+    "pragma",
+    "solidity",
+    "contract",
+    "contracts",
+    "import",
+    "forge",
+    "hardhat",
+    "foundry",
+    "truffle",
+    "compile",
+    "compiled",
+    "compiling",
+    "file",
+    "src",
+];
+
+static NAME_RE: OnceLock<Regex> = OnceLock::new();
+
+fn get_name_re() -> &'static Regex {
+    NAME_RE.get_or_init(|| Regex::new(NAME_SRC).unwrap())
+}
+
+/// The name must end in `.sol` and its own label needs a letter, so
+/// numeric values such as `1.sol` are not reported.
+fn is_valid_name(text: &str) -> bool {
+    let Some(name) = text.strip_suffix(".sol") else {
+        return false;
+    };
+    let label = name.rsplit('.').next().unwrap_or_default();
+    text.len() <= 253 && label.bytes().any(|b| b.is_ascii_alphabetic())
+}
+
+fn has_word(text: &str, m: &Match, words: &[&str]) -> bool {
+    let end = m.index + m.value.len();
+    let window = [
+        grapheme::before(text, m.index, CONTEXT_WINDOW),
+        grapheme::after(text, end, CONTEXT_WINDOW),
+    ];
+    window.iter().any(|side| {
+        side.split(|c: char| !c.is_ascii_alphanumeric())
+            .any(|w| words.contains(&w.to_ascii_lowercase().as_str()))
+    })
+}
+
+/// Whether the value reads as a Solidity file: written in capitals
+/// (`Token.sol`), ending a path, or next to a Solidity word.
+fn looks_like_file(text: &str, m: &Match) -> bool {
+    let in_path = text[..m.index].ends_with(['/', '\\']);
+    m.value.bytes().any(|b| b.is_ascii_uppercase()) || in_path || has_word(text, m, SOLIDITY_WORDS)
+}
+
+/// Drops names that look like Solidity file names unless an SNS word is
+/// nearby, or every name without one under `sns_require_context`.
+pub(crate) fn filter_filenames(text: &str, matches: &mut Vec<Match>, options: &DetectorOptions) {
+    matches.retain(|m| {
+        if m.rule_id != NAME.rule_id || has_word(text, m, CONTEXT_WORDS) {
+            return true;
+        }
+        !options.sns_require_context && !looks_like_file(text, m)
+    });
+}
+
+pub(crate) const NAME: Pattern = Pattern {
+    type_: "sns",
+    rule_id: "sns.name",
+    chain: "solana",
+    source: NAME_SRC,
+    regex: get_name_re,
+    validate: Some(is_valid_name),
+};
@@ -0,0 +1,69 @@
+//! Solana base58 addresses and transaction signatures.
+
+use crate::Pattern;
+use regex::Regex;
+use std::sync::OnceLock;
+
+const SOL_SRC: &str = r"\b[1-9A-HJ-NP-Za-km-z]{32,44}\b";
+const SOL_TRUNCATED_SRC: &str =
+    r"\b[1-9A-HJ-NP-Za-km-z]{3,10}(?:\.{3}|…)[1-9A-HJ-NP-Za-km-z]{3,10}\b";
+const SOL_TX_SIG_SRC: &str = r"\b[1-9A-HJ-NP-Za-km-z]{86,88}\b";
+const SOL_TX_TRUNCATED_SRC: &str =
+    r"\b[1-9A-HJ-NP-Za-km-z]{4,12}(?:\.{3}|…)[1-9A-HJ-NP-Za-km-z]{4,12}\b";
+
+static SOL_RE: OnceLock<Regex> = OnceLock::new();
+static SOL_TRUNCATED_RE: OnceLock<Regex> = OnceLock::new();
+static SOL_TX_SIG_RE: OnceLock<Regex> = OnceLock::new();
+static SOL_TX_TRUNCATED_RE: OnceLock<Regex> = OnceLock::new();
+
+fn get_sol_re() -> &'static Regex {
+    SOL_RE.get_or_init(|| Regex::new(SOL_SRC).unwrap())
+}
+
+fn get_sol_truncated_re() -> &'static Regex {
+    SOL_TRUNCATED_RE.get_or_init(|| Regex::new(SOL_TRUNCATED_SRC).unwrap())
+}
+
+fn get_sol_tx_sig_re() -> &'static Regex {
+    SOL_TX_SIG_RE.get_or_init(|| Regex::new(SOL_TX_SIG_SRC).unwrap())
+}
+
+fn get_sol_tx_truncated_re() -> &'static Regex {
+    SOL_TX_TRUNCATED_RE.get_or_init(|| Regex::new(SOL_TX_TRUNCATED_SRC).unwrap())
+}
+
+pub(crate) const TX_SIGNATURE: Pattern = Pattern {
+    type_: "sol_tx_sig",
+    rule_id: "sol.tx_signature",
+    chain: "solana",
+    source: SOL_TX_SIG_SRC,
+    regex: get_sol_tx_sig_re,
+    validate: None,
+};
+
+pub(crate) const TX_TRUNCATED: Pattern = Pattern {
+    type_: "sol_tx_truncated",
+    rule_id: "sol.tx_signature.truncated",
+    chain: "solana",
+    source: SOL_TX_TRUNCATED_SRC,
+    regex: get_sol_tx_truncated_re,
+    validate: None,
+};
+
+pub(crate) const ADDRESS: Pattern = Pattern {
+    type_: "sol",
+    rule_id: "sol.address",
+    chain: "solana",
+    source: SOL_SRC,
+    regex: get_sol_re,
+    validate: None,
+};
+
+pub(crate) const ADDRESS_TRUNCATED: Pattern = Pattern {
+    type_: "sol_truncated",
+    rule_id: "sol.address.truncated",
+    chain: "solana",
+    source: SOL_TRUNCATED_SRC,
+    regex: get_sol_truncated_re,
+    validate: None,
+};
@@ -0,0 +1,83 @@
+//! Substrate SS58 addresses (Polkadot, Kusama and generic Substrate chains):
+//! Base58 of network prefix + 32-byte account id + 2-byte Blake2b checksum.
+//! The checksum keeps ordinary Base58-looking words from being flagged.
+
+use crate::Pattern;
+use blake2::{Blake2b512, Digest};
+use regex::Regex;
+use std::sync::OnceLock;
+
+const SS58_SRC: &str = r"\b[1-9A-HJ-NP-Za-km-z]{46,48}\b";
+
+const POLKADOT_PREFIX: u16 = 0;
+const KUSAMA_PREFIX: u16 = 2;
+
+static SS58_RE: OnceLock<Regex> = OnceLock::new();
+
+fn get_ss58_re() -> &'static Regex {
+    SS58_RE.get_or_init(|| Regex::new(SS58_SRC).unwrap())
+}
+
+/// Decodes an SS58 account address and verifies its checksum, returning the
+/// network prefix.
+fn decode_prefix(value: &str) -> Option<u16> {
+    let bytes = bs58::decode(value).into_vec().ok()?;
+    let (prefix, prefix_len) = match *bytes.first()? {
+        b @ 0..=63 => (b as u16, 1),
+        b @ 64..=127 => {
+            let b1 = *bytes.get(1)? as u16;
+            let b0 = b as u16;
+            (((b0 & 0x3f) << 2) | (b1 >> 6) | ((b1 & 0x3f) << 8), 2)
+        }
+        _ => return None,
+    };
+    if bytes.len() != prefix_len + 32 + 2 {
+        return None;
+    }
+
+    let (body, checksum) = bytes.split_at(bytes.len() - 2);
+    let hash = Blake2b512::new()
+        .chain_update(b"SS58PRE")
+        .chain_update(body)
+        .finalize();
+    (hash[..2] == *checksum).then_some(prefix)
+}
+
+fn is_polkadot(value: &str) -> bool {
+    decode_prefix(value) == Some(POLKADOT_PREFIX)
+}
+
+fn is_kusama(value: &str) -> bool {
+    decode_prefix(value) == Some(KUSAMA_PREFIX)
+}
+
+fn is_other_substrate(value: &str) -> bool {
+    decode_prefix(value).is_some_and(|p| p != POLKADOT_PREFIX && p != KUSAMA_PREFIX)
+}
+
+pub(crate) const POLKADOT_ADDRESS: Pattern = Pattern {
+    type_: "dot_address",
+    rule_id: "polkadot.address",
+    chain: "polkadot",
+    source: SS58_SRC,
+    regex: get_ss58_re,
+    validate: Some(is_polkadot),
+};
+
+pub(crate) const KUSAMA_ADDRESS: Pattern = Pattern {
+    type_: "ksm_address",
+    rule_id: "kusama.address",
+    chain: "kusama",
+    source: SS58_SRC,
+    regex: get_ss58_re,
+    validate: Some(is_kusama),
+};
+
+pub(crate) const SUBSTRATE_ADDRESS: Pattern = Pattern {
+    type_: "substrate_address",
+    rule_id: "substrate.address",
+    chain: "substrate",
+    source: SS58_SRC,
+    regex: get_ss58_re,
+    validate: Some(is_other_substrate),
+};
@@ -0,0 +1,84 @@
+//! Stacks addresses: `S`, a version character, and the c32 encoding (a
+//! Crockford-style base32) of a 20-byte hash followed by a four-byte double
+//! SHA-256 checksum over the version byte and hash. `SP`/`SM` are mainnet
+//! single- and multi-signature addresses, `ST`/`SN` their testnet forms.
+
+use crate::{Match, Pattern};
+use regex::Regex;
+use sha2::{Digest, Sha256};
+use std::sync::OnceLock;
+
+const ADDRESS_SRC: &str = r"\bS[PMTN][0-9A-HJKMNP-TV-Z]{20,39}\b";
+
+const C32_ALPHABET: &[u8] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Version characters and the network each one names.
+const VERSIONS: &[(char, &str)] = &[
+    ('P', "mainnet"),
+    ('M', "mainnet"),
+    ('T', "testnet"),
+    ('N', "testnet"),
+];
+
+static ADDRESS_RE: OnceLock<Regex> = OnceLock::new();
+
+fn get_address_re() -> &'static Regex {
+    ADDRESS_RE.get_or_init(|| Regex::new(ADDRESS_SRC).unwrap())
+}
+
+fn c32_digit(c: u8) -> Option<u8> {
+    C32_ALPHABET.iter().position(|&a| a == c).map(|i| i as u8)
+}
+
+/// Decodes c32 as a big-endian number, each leading `0` standing for a
+/// leading zero byte.
+fn c32_decode(value: &str) -> Option<Vec<u8>> {
+    let mut number: Vec<u8> = Vec::new();
+    for c in value.bytes() {
+        let mut carry = c32_digit(c)? as u32;
+        for byte in number.iter_mut().rev() {
+            carry += (*byte as u32) << 5;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            number.insert(0, carry as u8);
+            carry >>= 8;
+        }
+    }
+    let zeros = value.bytes().take_while(|&c| c == b'0').count();
+    let mut bytes = vec![0; zeros];
+    bytes.extend(number.into_iter().skip_while(|&b| b == 0));
+    Some(bytes)
+}
+
+fn is_valid_address(value: &str) -> bool {
+    let Some(version) = c32_digit(value.as_bytes()[1]) else {
+        return false;
+    };
+    let Some(bytes) = c32_decode(&value[2..]).filter(|b| b.len() == 24) else {
+        return false;
+    };
+    let (hash, checksum) = bytes.split_at(20);
+    let digest = Sha256::digest(Sha256::digest([&[version][..], hash].concat()));
+    digest[..4] == *checksum
+}
+
+/// Sets `network` from the version character on every Stacks match.
+pub(crate) fn describe_networks(matches: &mut [Match]) {
+    for m in matches.iter_mut().filter(|m| m.rule_id == ADDRESS.rule_id) {
+        let version = m.value[1..].chars().next();
+        if let Some((_, network)) = VERSIONS.iter().find(|(v, _)| Some(*v) == version) {
+            m.network = Some(network.to_string());
+        }
+    }
+}
+
+pub(crate) const ADDRESS: Pattern = Pattern {
+    type_: "stx_address",
+    rule_id: "stacks.address",
+    chain: "stacks",
+    source: ADDRESS_SRC,
+    regex: get_address_re,
+    validate: Some(is_valid_address),
+};
@@ -0,0 +1,67 @@
+//! Starknet addresses: field elements written as `0x` + up to 64 hex
+//! digits, often with leading zeros stripped. Full-width ones share the
+//! shape of EVM transaction hashes and shorter ones of arbitrary hex blobs,
+//! so both are reported only when a Starknet word sits shortly before the
+//! value or the caller opts in with `DetectorOptions::starknet`.
+
+use crate::move_address::{nearest, words_before};
+use crate::{DetectorOptions, Match, Pattern};
+use regex::Regex;
+use std::sync::OnceLock;
+
+// Between an EVM address (40) and a full-width felt (64, claimed as a tx hash).
+const ADDRESS_SRC: &str = r"\b0x[0-9a-fA-F]{41,63}\b";
+
+/// The Stark field prime, 2^251 + 17·2^192 + 1, without leading zeros.
+const FIELD_PRIME: &str = "800000000000011000000000000000000000000000000000000000000000001";
+
+const CONTEXT_WORDS: &[&str] = &["starknet", "stark", "strk", "argent", "braavos"];
+const TX_WORDS: &[&str] = &["tx", "txn", "transaction", "hash"];
+
+static ADDRESS_RE: OnceLock<Regex> = OnceLock::new();
+
+fn get_address_re() -> &'static Regex {
+    ADDRESS_RE.get_or_init(|| Regex::new(ADDRESS_SRC).unwrap())
+}
+
+/// Whether the value is a field element, i.e. below the Stark prime.
+fn is_felt(value: &str) -> bool {
+    let digits = value[2..].trim_start_matches('0').to_ascii_lowercase();
+    digits.len() < FIELD_PRIME.len()
+        || digits.len() == FIELD_PRIME.len() && digits.as_str() < FIELD_PRIME
+}
+
+/// Keeps short-form Starknet matches only with context or opt-in, and
+/// relabels full-width `evm.tx_hash` felts the same way unless a
+/// transaction word is nearer than any Starknet word.
+pub(crate) fn relabel(text: &str, matches: &mut Vec<Match>, options: &DetectorOptions) {
+    let enabled = !options.disabled.iter().any(|t| t == ADDRESS.type_);
+    matches.retain_mut(|m| {
+        let short = m.rule_id == ADDRESS.rule_id;
+        if !short && (m.rule_id != "evm.tx_hash" || !enabled || !is_felt(&m.value)) {
+            return true;
+        }
+        let words = words_before(text, m.index);
+        let starknet = nearest(&words, CONTEXT_WORDS);
+        let is_address = match (starknet, nearest(&words, TX_WORDS)) {
+            (Some(s), Some(t)) => s < t,
+            (Some(_), None) => true,
+            (None, Some(_)) => false,
+            (None, None) => options.starknet,
+        };
+        if is_address {
+            m.type_ = ADDRESS.type_.to_string();
+            m.rule_id = ADDRESS.rule_id.to_string();
+        }
+        is_address || !short
+    });
+}
+
+pub(crate) const ADDRESS: Pattern = Pattern {
+    type_: "starknet_address",
+    rule_id: "starknet.address",
+    chain: "starknet",
+    source: ADDRESS_SRC,
+    regex: get_address_re,
+    validate: Some(is_felt),
+};
@@ -0,0 +1,65 @@
+//! Stellar account ids: `G` + 55 base32 characters encoding a version byte,
+//! a 32-byte ed25519 key and a CRC16-XModem checksum.
+
+use crate::Pattern;
+use crate::chains::base32;
+use regex::Regex;
+use std::sync::OnceLock;
+
+const STELLAR_SRC: &str = r"\bG[A-Z2-7]{55}\b";
+const STELLAR_TRUNCATED_SRC: &str = r"\bG[A-Z2-7]{3,10}(?:\.{3}|…)[A-Z2-7]{3,10}\b";
+
+/// Version byte of an ed25519 public key (`G`).
+const ACCOUNT_VERSION: u8 = 6 << 3;
+
+static STELLAR_RE: OnceLock<Regex> = OnceLock::new();
+static STELLAR_TRUNCATED_RE: OnceLock<Regex> = OnceLock::new();
+
+fn get_stellar_re() -> &'static Regex {
+    STELLAR_RE.get_or_init(|| Regex::new(STELLAR_SRC).unwrap())
+}
+
+fn get_stellar_truncated_re() -> &'static Regex {
+    STELLAR_TRUNCATED_RE.get_or_init(|| Regex::new(STELLAR_TRUNCATED_SRC).unwrap())
+}
+
+fn crc16_xmodem(data: &[u8]) -> u16 {
+    data.iter().fold(0u16, |crc, &b| {
+        (0..8).fold(crc ^ (b as u16) << 8, |crc, _| {
+            if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            }
+        })
+    })
+}
+
+fn is_valid_stellar(value: &str) -> bool {
+    let Some(bytes) = base32::decode(value) else {
+        return false;
+    };
+    if bytes.len() != 35 || bytes[0] != ACCOUNT_VERSION {
+        return false;
+    }
+    let checksum = u16::from_le_bytes([bytes[33], bytes[34]]);
+    crc16_xmodem(&bytes[..33]) == checksum
+}
+
+pub(crate) const ADDRESS: Pattern = Pattern {
+    type_: "xlm_address",
+    rule_id: "stellar.address",
+    chain: "stellar",
+    source: STELLAR_SRC,
+    regex: get_stellar_re,
+    validate: Some(is_valid_stellar),
+};
+
+pub(crate) const ADDRESS_TRUNCATED: Pattern = Pattern {
+    type_: "xlm_truncated",
+    rule_id: "stellar.address.truncated",
+    chain: "stellar",
+    source: STELLAR_TRUNCATED_SRC,
+    regex: get_stellar_truncated_re,
+    validate: None,
+};
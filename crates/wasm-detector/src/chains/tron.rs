@@ -0,0 +1,29 @@
+//! Tron addresses: `T` + 33 Base58 characters, Base58Check-encoded with
+//! version byte `0x41`. The checksum keeps them apart from Solana addresses,
+//! which share the alphabet and length range.
+
+use crate::Pattern;
+use crate::chains::base58check;
+use regex::Regex;
+use std::sync::OnceLock;
+
+const TRON_SRC: &str = r"\bT[1-9A-HJ-NP-Za-km-z]{33}\b";
+
+static TRON_RE: OnceLock<Regex> = OnceLock::new();
+
+fn get_tron_re() -> &'static Regex {
+    TRON_RE.get_or_init(|| Regex::new(TRON_SRC).unwrap())
+}
+
+fn is_valid_tron(value: &str) -> bool {
+    base58check::decode(value).is_some_and(|payload| payload.len() == 21 && payload[0] == 0x41)
+}
+
+pub(crate) const ADDRESS: Pattern = Pattern {
+    type_: "trx_address",
+    rule_id: "tron.address",
+    chain: "tron",
+    source: TRON_SRC,
+    regex: get_tron_re,
+    validate: Some(is_valid_tron),
+};
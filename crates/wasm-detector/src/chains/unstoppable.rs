@@ -0,0 +1,72 @@
+//! Unstoppable Domains names (`brad.crypto`, `alice.nft`, `pay.bob.x`).
+//! Any dotted name is a candidate and the validator keeps those ending in a
+//! TLD from `TLDS`, so the list is the single place to add new TLDs.
+//! Trailing labels are matched too and rejected with the rest, so
+//! `www.crypto.com` is not reported as `www.crypto`.
+
+use crate::Pattern;
+use regex::Regex;
+use std::sync::OnceLock;
+
+const NAME_SRC: &str = r"(?i)\b(?:[a-z0-9](?:[a-z0-9-]{0,61}[a-z0-9])?\.)+[a-z0-9]{1,16}\b";
+
+/// TLDs minted by Unstoppable Domains. `.go` is left out: it is far more
+/// often a Go source file than a name.
+const TLDS: &[&str] = &[
+    "crypto",
+    "nft",
+    "wallet",
+    "x",
+    "dao",
+    "blockchain",
+    "bitcoin",
+    "888",
+    "zil",
+    "polygon",
+    "unstoppable",
+    "klever",
+    "hi",
+    "kresus",
+    "anime",
+    "manga",
+    "binanceus",
+    "austin",
+    "pudgy",
+    "bitget",
+    "pog",
+    "clay",
+    "stepn",
+];
+
+/// Names are at most 253 characters, and the label in front of the TLD
+/// needs a letter besides a leading `v`, so version strings such as `2.x`
+/// and `v2.x` stay untouched.
+fn is_valid_name(text: &str) -> bool {
+    let lower = text.to_ascii_lowercase();
+    let Some((name, tld)) = lower.rsplit_once('.') else {
+        return false;
+    };
+    let label = name.rsplit('.').next().unwrap_or_default();
+    TLDS.contains(&tld)
+        && text.len() <= 253
+        && label
+            .strip_prefix('v')
+            .unwrap_or(label)
+            .bytes()
+            .any(|b| b.is_ascii_alphabetic())
+}
+
+static NAME_RE: OnceLock<Regex> = OnceLock::new();
+
+fn get_name_re() -> &'static Regex {
+    NAME_RE.get_or_init(|| Regex::new(NAME_SRC).unwrap())
+}
+
+pub(crate) const NAME: Pattern = Pattern {
+    type_: "ud_name",
+    rule_id: "unstoppable.name",
+    chain: "unstoppable",
+    source: NAME_SRC,
+    regex: get_name_re,
+    validate: Some(is_valid_name),
+};
@@ -0,0 +1,118 @@
+//! XRP Ledger addresses: classic `r...` accounts and `X...` X-addresses
+//! (account plus destination tag), both Base58Check-encoded with the Ripple
+//! alphabet. Only mainnet X-addresses are matched. Shortened forms carry no
+//! checksum, so `gate_truncated` keeps them as XRP only with an XRP word
+//! nearby.
+
+use crate::chains::{base58check, reassign};
+use crate::{Match, Pattern, grapheme};
+use regex::Regex;
+use std::sync::OnceLock;
+
+const CLASSIC_SRC: &str = r"\br[1-9A-HJ-NP-Za-km-z]{24,34}\b";
+const CLASSIC_TRUNCATED_SRC: &str =
+    r"\br[1-9A-HJ-NP-Za-km-z]{3,10}(?:\.{3}|…)[1-9A-HJ-NP-Za-km-z]{3,10}\b";
+const X_ADDRESS_SRC: &str = r"\bX[1-9A-HJ-NP-Za-km-z]{46}\b";
+const X_ADDRESS_TRUNCATED_SRC: &str =
+    r"\bX[1-9A-HJ-NP-Za-km-z]{3,10}(?:\.{3}|…)[1-9A-HJ-NP-Za-km-z]{3,10}\b";
+
+/// Grapheme clusters on either side of a shortened address searched for
+/// context words.
+const CONTEXT_WINDOW: usize = 48;
+
+const CONTEXT_WORDS: &[&str] = &["xrp", "xrpl", "ripple", "xrpscan", "bithomp", "xumm"];
+
+/// Version bytes of a mainnet X-address.
+const X_ADDRESS_PREFIX: [u8; 2] = [0x05, 0x44];
+
+static CLASSIC_RE: OnceLock<Regex> = OnceLock::new();
+static CLASSIC_TRUNCATED_RE: OnceLock<Regex> = OnceLock::new();
+static X_ADDRESS_RE: OnceLock<Regex> = OnceLock::new();
+static X_ADDRESS_TRUNCATED_RE: OnceLock<Regex> = OnceLock::new();
+
+fn get_classic_re() -> &'static Regex {
+    CLASSIC_RE.get_or_init(|| Regex::new(CLASSIC_SRC).unwrap())
+}
+
+fn get_classic_truncated_re() -> &'static Regex {
+    CLASSIC_TRUNCATED_RE.get_or_init(|| Regex::new(CLASSIC_TRUNCATED_SRC).unwrap())
+}
+
+fn get_x_address_re() -> &'static Regex {
+    X_ADDRESS_RE.get_or_init(|| Regex::new(X_ADDRESS_SRC).unwrap())
+}
+
+fn get_x_address_truncated_re() -> &'static Regex {
+    X_ADDRESS_TRUNCATED_RE.get_or_init(|| Regex::new(X_ADDRESS_TRUNCATED_SRC).unwrap())
+}
+
+/// Version byte 0x00 followed by a 20-byte account id.
+fn is_valid_classic(value: &str) -> bool {
+    base58check::decode_with(value, bs58::Alphabet::RIPPLE)
+        .is_some_and(|payload| payload.len() == 21 && payload[0] == 0x00)
+}
+
+/// Two version bytes, a 20-byte account id, a tag flag and an 8-byte tag.
+fn is_valid_x_address(value: &str) -> bool {
+    base58check::decode_with(value, bs58::Alphabet::RIPPLE)
+        .is_some_and(|payload| payload.len() == 31 && payload[..2] == X_ADDRESS_PREFIX)
+}
+
+fn has_context(text: &str, m: &Match) -> bool {
+    let end = m.index + m.value.len();
+    let window = [
+        grapheme::before(text, m.index, CONTEXT_WINDOW),
+        grapheme::after(text, end, CONTEXT_WINDOW),
+    ];
+    window.iter().any(|side| {
+        side.split(|c: char| !c.is_ascii_alphanumeric())
+            .any(|w| CONTEXT_WORDS.contains(&w.to_ascii_lowercase().as_str()))
+    })
+}
+
+/// Passes shortened `r…`/`X…` values with no XRP word nearby to the next
+/// pattern that would have claimed them, e.g. a Solana short form.
+pub(crate) fn gate_truncated(text: &str, matches: &mut [Match], patterns: &[&'static Pattern]) {
+    let rules = [ADDRESS_TRUNCATED.rule_id, X_ADDRESS_TRUNCATED.rule_id];
+    for m in matches.iter_mut() {
+        if rules.contains(&m.rule_id.as_str()) && !has_context(text, m) {
+            reassign(m, patterns);
+        }
+    }
+}
+
+pub(crate) const ADDRESS: Pattern = Pattern {
+    type_: "xrp_address",
+    rule_id: "xrp.address",
+    chain: "xrp",
+    source: CLASSIC_SRC,
+    regex: get_classic_re,
+    validate: Some(is_valid_classic),
+};
+
+pub(crate) const ADDRESS_TRUNCATED: Pattern = Pattern {
+    type_: "xrp_truncated",
+    rule_id: "xrp.address.truncated",
+    chain: "xrp",
+    source: CLASSIC_TRUNCATED_SRC,
+    regex: get_classic_truncated_re,
+    validate: None,
+};
+
+pub(crate) const X_ADDRESS: Pattern = Pattern {
+    type_: "xrp_x_address",
+    rule_id: "xrp.x_address",
+    chain: "xrp",
+    source: X_ADDRESS_SRC,
+    regex: get_x_address_re,
+    validate: Some(is_valid_x_address),
+};
+
+pub(crate) const X_ADDRESS_TRUNCATED: Pattern = Pattern {
+    type_: "xrp_x_truncated",
+    rule_id: "xrp.x_address.truncated",
+    chain: "xrp",
+    source: X_ADDRESS_TRUNCATED_SRC,
+    regex: get_x_address_truncated_re,
+    validate: None,
+};
@@ -0,0 +1,74 @@
+//! Zcash addresses: transparent `t1...` (P2PKH) and `t3...` (P2SH),
+//! Base58Check-encoded with two version bytes; Sapling shielded `zs1...`
+//! (bech32) and unified `u1...` (bech32m). Each is its own match type so a
+//! policy can treat shielded and transparent addresses differently.
+
+use crate::Pattern;
+use crate::bech32::{self, Variant};
+use crate::chains::base58check;
+use regex::Regex;
+use std::sync::OnceLock;
+
+const TRANSPARENT_SRC: &str = r"\bt[13][1-9A-HJ-NP-Za-km-z]{33}\b";
+const SAPLING_SRC: &str = r"(?i)\bzs1[qpzry9x8gf2tvdw0s3jn54khce6mua7l]{75}\b";
+const UNIFIED_SRC: &str = r"(?i)\bu1[qpzry9x8gf2tvdw0s3jn54khce6mua7l]{100,}\b";
+
+/// Version bytes of mainnet P2PKH (`t1`) and P2SH (`t3`) addresses.
+const TRANSPARENT_VERSIONS: [[u8; 2]; 2] = [[0x1c, 0xb8], [0x1c, 0xbd]];
+
+static TRANSPARENT_RE: OnceLock<Regex> = OnceLock::new();
+static SAPLING_RE: OnceLock<Regex> = OnceLock::new();
+static UNIFIED_RE: OnceLock<Regex> = OnceLock::new();
+
+fn get_transparent_re() -> &'static Regex {
+    TRANSPARENT_RE.get_or_init(|| Regex::new(TRANSPARENT_SRC).unwrap())
+}
+
+fn get_sapling_re() -> &'static Regex {
+    SAPLING_RE.get_or_init(|| Regex::new(SAPLING_SRC).unwrap())
+}
+
+fn get_unified_re() -> &'static Regex {
+    UNIFIED_RE.get_or_init(|| Regex::new(UNIFIED_SRC).unwrap())
+}
+
+fn is_valid_transparent(value: &str) -> bool {
+    base58check::decode(value).is_some_and(|payload| {
+        payload.len() == 22 && TRANSPARENT_VERSIONS.iter().any(|v| payload[..2] == *v)
+    })
+}
+
+fn is_valid_sapling(value: &str) -> bool {
+    bech32::decode(value).is_some_and(|d| d.hrp == "zs" && d.variant == Variant::Bech32)
+}
+
+fn is_valid_unified(value: &str) -> bool {
+    bech32::decode(value).is_some_and(|d| d.hrp == "u" && d.variant == Variant::Bech32m)
+}
+
+pub(crate) const TRANSPARENT_ADDRESS: Pattern = Pattern {
+    type_: "zec_transparent_address",
+    rule_id: "zcash.transparent_address",
+    chain: "zcash",
+    source: TRANSPARENT_SRC,
+    regex: get_transparent_re,
+    validate: Some(is_valid_transparent),
+};
+
+pub(crate) const SHIELDED_ADDRESS: Pattern = Pattern {
+    type_: "zec_shielded_address",
+    rule_id: "zcash.shielded_address",
+    chain: "zcash",
+    source: SAPLING_SRC,
+    regex: get_sapling_re,
+    validate: Some(is_valid_sapling),
+};
+
+pub(crate) const UNIFIED_ADDRESS: Pattern = Pattern {
+    type_: "zec_unified_address",
+    rule_id: "zcash.unified_address",
+    chain: "zcash",
+    source: UNIFIED_SRC,
+    regex: get_unified_re,
+    validate: Some(is_valid_unified),
+};
@@ -0,0 +1,231 @@
+//! Chunked scanning for single huge strings. The text is cut into chunks
+//! that are scanned one at a time, each widened on both sides by an overlap
+//! as long as the longest value any pattern can match, so a value that
+//! straddles a cut is found whole in exactly one chunk. Between chunks a
+//! host can yield to its event loop and report progress, which keeps the
+//! latency of each step bounded however long the text is; native builds can
+//! also spread the chunks over threads.
+
+use crate::cap::Capped;
+use crate::{
+    DetectorError, DetectorOptions, Match, PATTERNS, collect_with, group_and_cap, is_editable,
+    limited_regexes,
+};
+use regex::Regex;
+use std::ops::Range;
+use std::sync::{Arc, OnceLock};
+
+/// Chunk size used when the host does not pick one.
+pub const DEFAULT_CHUNK_BYTES: usize = 64 * 1024;
+
+/// Length assumed for patterns with no upper bound (deep links, whose query
+/// strings may run on).
+const UNBOUNDED_LEN: usize = 2 * 1024;
+
+static OVERLAP: OnceLock<usize> = OnceLock::new();
+
+/// Bytes each chunk is widened by on either side: the longest match any
+/// compiled pattern can produce.
+pub fn overlap() -> usize {
+    *OVERLAP.get_or_init(|| {
+        PATTERNS
+            .iter()
+            .map(|p| {
+                regex_syntax::parse(p.source)
+                    .ok()
+                    .and_then(|hir| hir.properties().maximum_len())
+                    .unwrap_or(UNBOUNDED_LEN)
+            })
+            .max()
+            .unwrap_or(0)
+    })
+}
+
+fn floor_boundary(text: &str, mut i: usize) -> usize {
+    while !text.is_char_boundary(i) {
+        i -= 1;
+    }
+    i
+}
+
+/// Splits `text` into char-aligned ranges of about `chunk_bytes`, which is
+/// raised to the overlap so no chunk costs less to scan than its margins.
+pub(crate) fn chunks(text: &str, chunk_bytes: usize) -> Vec<Range<usize>> {
+    let chunk_bytes = chunk_bytes.max(overlap());
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    while start < text.len() {
+        let mut end = (start + chunk_bytes).min(text.len());
+        while !text.is_char_boundary(end) {
+            end += 1;
+        }
+        chunks.push(start..end);
+        start = end;
+    }
+    chunks
+}
+
+/// Scans `chunk` plus its overlap and keeps the matches that start inside
+/// the chunk, with indexes into the whole text.
+pub(crate) fn scan_chunk(
+    text: &str,
+    chunk: &Range<usize>,
+    options: &DetectorOptions,
+    limited: Option<&[Regex]>,
+) -> Vec<Match> {
+    let start = floor_boundary(text, chunk.start.saturating_sub(overlap()));
+    let mut end = (chunk.end + overlap()).min(text.len());
+    while !text.is_char_boundary(end) {
+        end += 1;
+    }
+    collect_with(&text[start..end], options, limited)
+        .into_iter()
+        .filter_map(|mut m| {
+            m.shift(start);
+            if !chunk.contains(&m.index) {
+                return None;
+            }
+            m.editable = is_editable(&m, &options.editable_ranges);
+            Some(m)
+        })
+        .collect()
+}
+
+/// A scan that advances one chunk per `step`, for hosts that must yield
+/// between steps (a browser main thread) or show progress.
+pub struct ChunkedScan {
+    text: String,
+    options: DetectorOptions,
+    limited: Option<Arc<[Regex]>>,
+    chunks: Vec<Range<usize>>,
+    next: usize,
+    matches: Vec<Match>,
+}
+
+impl ChunkedScan {
+    /// Fails up front if the options' size limits cannot be met.
+    pub fn new(
+        text: impl Into<String>,
+        options: DetectorOptions,
+        chunk_bytes: usize,
+    ) -> Result<Self, DetectorError> {
+        let text = text.into();
+        let limited = limited_regexes(&options)?;
+        let chunks = chunks(&text, chunk_bytes);
+        Ok(ChunkedScan {
+            text,
+            options,
+            limited,
+            chunks,
+            next: 0,
+            matches: Vec::new(),
+        })
+    }
+
+    /// Scans the next chunk. Returns `false` once the whole text is done.
+    pub fn step(&mut self) -> bool {
+        let Some(chunk) = self.chunks.get(self.next) else {
+            return false;
+        };
+        let found = scan_chunk(&self.text, chunk, &self.options, self.limited.as_deref());
+        self.matches.extend(found);
+        self.next += 1;
+        self.next < self.chunks.len()
+    }
+
+    /// Bytes scanned so far and the text's total length.
+    pub fn progress(&self) -> (usize, usize) {
+        let done = self.next.checked_sub(1).map_or(0, |i| self.chunks[i].end);
+        (done, self.text.len())
+    }
+
+    /// Every match found, with `group_positions` and `max_matches` applied.
+    /// Chunks not yet stepped through are scanned first.
+    pub fn finish(mut self) -> Capped {
+        while self.step() {}
+        group_and_cap(self.matches, &self.options)
+    }
+}
+
+/// Scans `text` chunk by chunk, calling `on_progress(scanned, total)` after
+/// each chunk. Finds what `collect_matches_capped` finds, except that
+/// context words further than the overlap from a value are not seen.
+pub fn scan_chunked<F>(
+    text: &str,
+    options: &DetectorOptions,
+    chunk_bytes: usize,
+    mut on_progress: F,
+) -> Result<Capped, DetectorError>
+where
+    F: FnMut(usize, usize),
+{
+    let limited = limited_regexes(options)?;
+    let mut matches = Vec::new();
+    for chunk in chunks(text, chunk_bytes) {
+        matches.extend(scan_chunk(text, &chunk, options, limited.as_deref()));
+        on_progress(chunk.end, text.len());
+    }
+    Ok(group_and_cap(matches, options))
+}
+
+/// Like `scan_chunked`, yielding to the executor after each chunk.
+#[cfg(feature = "async")]
+pub async fn scan_chunked_async<F>(
+    text: &str,
+    options: &DetectorOptions,
+    chunk_bytes: usize,
+    mut on_progress: F,
+) -> Result<Capped, DetectorError>
+where
+    F: FnMut(usize, usize),
+{
+    let limited = limited_regexes(options)?;
+    let mut matches = Vec::new();
+    for chunk in chunks(text, chunk_bytes) {
+        matches.extend(scan_chunk(text, &chunk, options, limited.as_deref()));
+        on_progress(chunk.end, text.len());
+        crate::detector::YieldNow(false).await;
+    }
+    Ok(group_and_cap(matches, options))
+}
+
+/// Like `scan_chunked` without progress, scanning chunks on up to
+/// `available_parallelism` threads. Not available on wasm targets.
+#[cfg(not(target_family = "wasm"))]
+pub fn scan_chunked_parallel(
+    text: &str,
+    options: &DetectorOptions,
+    chunk_bytes: usize,
+) -> Result<Capped, DetectorError> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let limited = limited_regexes(options)?;
+    let chunks = chunks(text, chunk_bytes);
+    let threads = std::thread::available_parallelism()
+        .map_or(1, |n| n.get())
+        .min(chunks.len());
+    let next = AtomicUsize::new(0);
+    let mut found: Vec<(usize, Vec<Match>)> = std::thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut found = Vec::new();
+                    loop {
+                        let i = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(chunk) = chunks.get(i) else {
+                            break found;
+                        };
+                        found.push((i, scan_chunk(text, chunk, options, limited.as_deref())));
+                    }
+                })
+            })
+            .collect();
+        workers
+            .into_iter()
+            .flat_map(|w| w.join().unwrap())
+            .collect()
+    });
+    found.sort_by_key(|&(i, _)| i);
+    let matches = found.into_iter().flat_map(|(_, m)| m).collect();
+    Ok(group_and_cap(matches, options))
+}
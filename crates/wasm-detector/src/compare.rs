@@ -0,0 +1,60 @@
+//! Comparison keys: the normalized form under which two matched strings are
+//! the same value. Hex, bech32, cashaddr and name-service values are case
+//! insensitive (EIP-55 casing is only a checksum), while Base58 and base32
+//! alphabets are case sensitive and keep their casing. Either ellipsis style
+//! in a truncated value compares equal.
+
+/// Families whose values compare case-insensitively.
+const CASE_INSENSITIVE_FAMILIES: &[&str] = &[
+    "evm",
+    "aptos",
+    "sui",
+    "move",
+    "starknet",
+    "flow",
+    "bech32",
+    "cardano",
+    "avalanche",
+    "chia",
+    "harmony",
+    "multiversx",
+    "bitcoincash",
+    "kaspa",
+    "filecoin",
+    "hedera",
+    "ens",
+    "unstoppable",
+    "sns",
+    "near",
+];
+
+/// Case-insensitive rules in families whose other forms are case sensitive.
+const CASE_INSENSITIVE_RULES: &[&str] = &[
+    "bitcoin.segwit_address",
+    "bitcoin.taproot_address",
+    "litecoin.bech32_address",
+    "zcash.shielded_address",
+    "zcash.unified_address",
+    "pii.email",
+];
+
+/// Whether values reported under `rule_id` ignore letter case.
+pub fn folds_case(rule_id: &str) -> bool {
+    let family = rule_id.split('.').next().unwrap_or_default();
+    CASE_INSENSITIVE_FAMILIES.contains(&family)
+        || CASE_INSENSITIVE_RULES
+            .iter()
+            .any(|r| rule_id.starts_with(r))
+}
+
+/// The key hosts and the crate itself use for allowlists, dedup and
+/// first-seen tracking: `0x` values and case-insensitive families
+/// lowercased, `…` written as `...`.
+pub fn compare_key(rule_id: &str, value: &str) -> String {
+    let value = value.replace('…', "...");
+    if folds_case(rule_id) || value.starts_with("0x") {
+        value.to_ascii_lowercase()
+    } else {
+        value
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{PATTERNS, collect_matches, has_overlap};
+use serde::Serialize;
+
+/// A raw pattern hit that lost to the reported match during overlap resolution.
+#[derive(Serialize)]
+pub struct Candidate {
+    pub value: String,
+    pub index: usize,
+    pub type_: String,
+    pub rule_id: String,
+}
+
+/// A reported match together with every other family whose pattern also fired on it.
+#[derive(Serialize)]
+pub struct Confusion {
+    pub value: String,
+    pub index: usize,
+    pub type_: String,
+    pub rule_id: String,
+    pub also_matched: Vec<Candidate>,
+}
+
+/// Reports matches whose span was also claimed by a different pattern family,
+/// showing which alternatives were discarded by the priority order.
+pub fn find_confusions(text: &str) -> Vec<Confusion> {
+    let matches = collect_matches(text);
+
+    let mut candidates = Vec::new();
+    for pattern in PATTERNS {
+        for cap in pattern.regex().find_iter(text) {
+            if !pattern.accepts(cap.as_str()) {
+                continue;
+            }
+            candidates.push(Candidate {
+                value: cap.as_str().to_string(),
+                index: cap.start(),
+                type_: pattern.type_.to_string(),
+                rule_id: pattern.rule_id.to_string(),
+            });
+        }
+    }
+
+    matches
+        .into_iter()
+        .filter_map(|m| {
+            let claimed = std::slice::from_ref(&m);
+            let also_matched: Vec<Candidate> = candidates
+                .iter()
+                .filter(|c| c.type_ != m.type_)
+                .filter(|c| has_overlap(c.index, c.index + c.value.len(), claimed))
+                .map(|c| Candidate {
+                    value: c.value.clone(),
+                    index: c.index,
+                    type_: c.type_.clone(),
+                    rule_id: c.rule_id.clone(),
+                })
+                .collect();
+
+            if also_matched.is_empty() {
+                return None;
+            }
+
+            Some(Confusion {
+                value: m.value,
+                index: m.index,
+                type_: m.type_,
+                rule_id: m.rule_id,
+                also_matched,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,470 @@
+//! Idiomatic Rust interface to the detector, separate from the wasm surface:
+//! a builder validates configuration once, and scans yield matches that
+//! borrow their values from the input instead of copying them.
+//!
+//! A `Detector` is `Send + Sync`; one configured instance can be shared
+//! (e.g. in an `Arc`) across every worker of a server.
+
+use crate::cap::Capped;
+use crate::{DetectorError, DetectorOptions, collect_with, group_and_cap, limited_regexes};
+#[cfg(feature = "async")]
+use crate::{Pipeline, Prepared};
+use dashmap::{DashMap, DashSet};
+use regex::Regex;
+use std::collections::{BTreeMap, BTreeSet};
+#[cfg(feature = "async")]
+use std::future::Future;
+#[cfg(feature = "async")]
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, OnceLock};
+#[cfg(feature = "async")]
+use std::task::{Context, Poll};
+
+/// Result-cache size used when the builder does not set one.
+const DEFAULT_CACHE_CAPACITY: usize = 1024;
+
+/// Texts longer than this are never cached; they are unlikely to repeat and
+/// would dominate the cache's memory.
+const MAX_CACHED_TEXT: usize = 16 * 1024;
+
+/// Returns `Pending` once, waking itself, so the executor can run other tasks.
+#[cfg(feature = "async")]
+pub(crate) struct YieldNow(pub(crate) bool);
+
+#[cfg(feature = "async")]
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            return Poll::Ready(());
+        }
+        self.0 = true;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+static INTERNED: OnceLock<Mutex<BTreeSet<&'static str>>> = OnceLock::new();
+
+/// Returns a `'static` copy of a type, rule id, kind or chain name. Each
+/// distinct string is leaked once and kept for the life of the process, so
+/// the set grows with every new one seen: beyond the pattern table's ids,
+/// `type_map` renames and loaded rules bundles add their own.
+fn intern(s: &str) -> &'static str {
+    let mut interned = INTERNED
+        .get_or_init(|| Mutex::new(BTreeSet::new()))
+        .lock()
+        .unwrap();
+    if let Some(existing) = interned.get(s) {
+        return existing;
+    }
+    let leaked: &'static str = Box::leak(s.to_string().into_boxed_str());
+    interned.insert(leaked);
+    leaked
+}
+
+/// A detected value borrowed from the scanned text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Match<'t> {
+    pub value: &'t str,
+    pub index: usize,
+    pub type_: &'static str,
+    pub rule_id: &'static str,
+    /// Normalized value for allowlist checks and dedup.
+    pub compare_key: String,
+    pub metadata: BTreeMap<String, String>,
+    pub possible_chains: Vec<&'static str>,
+    /// The checksum-valid form of an OCR-repaired value.
+    pub corrected_value: Option<String>,
+    /// Whether this detector had not seen the address before in its
+    /// session. Always `false` for values that are not addresses.
+    pub first_seen: bool,
+    /// Whether the value overlaps a host-supplied editable range.
+    pub editable: bool,
+    /// Byte offsets of every occurrence, when `group_positions` folded
+    /// repeats into this match.
+    pub positions: Vec<u32>,
+    /// Mainnet, testnet or another network of the chain, when known.
+    pub network: Option<String>,
+    /// Canonical equivalent on a related chain, when normalizing.
+    pub normalized: Option<String>,
+    /// Chain family, kind and form of the value; see `crate::Match`.
+    pub chain: Option<&'static str>,
+    pub kind: &'static str,
+    pub variant: Option<&'static str>,
+}
+
+impl<'t> Match<'t> {
+    fn borrow_from(text: &'t str, m: crate::Match) -> Self {
+        Match {
+            value: &text[m.index..m.index + m.value.len()],
+            index: m.index,
+            type_: intern(&m.type_),
+            rule_id: intern(&m.rule_id),
+            compare_key: m.compare_key,
+            metadata: m.metadata,
+            possible_chains: m.possible_chains.iter().map(|c| intern(c)).collect(),
+            corrected_value: m.corrected_value,
+            first_seen: false,
+            editable: m.editable,
+            positions: m.positions,
+            network: m.network,
+            normalized: m.normalized,
+            chain: m.chain.as_deref().map(intern),
+            kind: intern(&m.kind),
+            variant: m.variant.as_deref().map(intern),
+        }
+    }
+
+    /// Byte offset one past the end of the value.
+    pub fn end(&self) -> usize {
+        self.index + self.value.len()
+    }
+
+    /// Converts into the owned form used by the wasm bindings.
+    pub fn to_owned_match(&self) -> crate::Match {
+        crate::Match {
+            value: self.value.to_string(),
+            index: self.index,
+            type_: self.type_.to_string(),
+            rule_id: self.rule_id.to_string(),
+            compare_key: self.compare_key.clone(),
+            metadata: self.metadata.clone(),
+            possible_chains: self.possible_chains.iter().map(|c| c.to_string()).collect(),
+            corrected: self.corrected_value.is_some(),
+            corrected_value: self.corrected_value.clone(),
+            editable: self.editable,
+            positions: self.positions.clone(),
+            network: self.network.clone(),
+            normalized: self.normalized.clone(),
+            chain: self.chain.map(str::to_string),
+            kind: self.kind.to_string(),
+            variant: self.variant.map(str::to_string),
+        }
+    }
+}
+
+/// All matches of one scan, in text order.
+#[derive(Debug, Clone, Default)]
+pub struct ScanResult<'t> {
+    matches: Vec<Match<'t>>,
+    dropped: BTreeMap<String, usize>,
+}
+
+impl<'t> ScanResult<'t> {
+    /// Occurrences `max_matches` dropped, per type.
+    pub fn dropped(&self) -> &BTreeMap<String, usize> {
+        &self.dropped
+    }
+
+    pub fn len(&self) -> usize {
+        self.matches.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.matches.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Match<'t>> {
+        self.matches.iter()
+    }
+
+    /// Matches of a single type, e.g. `"fullAddress"`.
+    pub fn of_type<'a>(&'a self, type_: &'a str) -> impl Iterator<Item = &'a Match<'t>> {
+        self.matches.iter().filter(move |m| m.type_ == type_)
+    }
+
+    pub fn into_vec(self) -> Vec<Match<'t>> {
+        self.matches
+    }
+}
+
+impl<'t> IntoIterator for ScanResult<'t> {
+    type Item = Match<'t>;
+    type IntoIter = std::vec::IntoIter<Match<'t>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.matches.into_iter()
+    }
+}
+
+impl<'t> FromIterator<Match<'t>> for ScanResult<'t> {
+    fn from_iter<I: IntoIterator<Item = Match<'t>>>(iter: I) -> Self {
+        ScanResult {
+            matches: iter.into_iter().collect(),
+            dropped: BTreeMap::new(),
+        }
+    }
+}
+
+/// Configures a `Detector`. Apart from `cache_capacity`, every setter
+/// mirrors a `DetectorOptions` field.
+pub struct DetectorBuilder {
+    options: DetectorOptions,
+    cache_capacity: usize,
+}
+
+impl Default for DetectorBuilder {
+    fn default() -> Self {
+        DetectorBuilder::from_options(DetectorOptions::default())
+    }
+}
+
+impl DetectorBuilder {
+    pub fn new() -> Self {
+        DetectorBuilder::default()
+    }
+
+    /// Starts from an existing options value, e.g. a site profile.
+    pub fn from_options(options: DetectorOptions) -> Self {
+        DetectorBuilder {
+            options,
+            cache_capacity: DEFAULT_CACHE_CAPACITY,
+        }
+    }
+
+    /// Number of distinct short texts whose results are remembered; repeated
+    /// inputs (the same DOM node, the same log line) skip rescanning. 0
+    /// disables the cache, which is advisable when validators or registries
+    /// are changed at runtime, since cached results are not invalidated.
+    /// Loading or clearing rules bundles does invalidate them.
+    pub fn cache_capacity(mut self, entries: usize) -> Self {
+        self.cache_capacity = entries;
+        self
+    }
+
+    pub fn promote(mut self, type_: &str) -> Self {
+        self.options.promote.push(type_.to_string());
+        self
+    }
+
+    pub fn demote(mut self, type_: &str) -> Self {
+        self.options.demote.push(type_.to_string());
+        self
+    }
+
+    pub fn disable(mut self, type_: &str) -> Self {
+        self.options.disabled.push(type_.to_string());
+        self
+    }
+
+    /// Switches truncated detection for a family on or off, keyed by the
+    /// full pattern's rule id (e.g. `"evm.address"`).
+    pub fn truncated(mut self, family: &str, enabled: bool) -> Self {
+        self.options.truncated.insert(family.to_string(), enabled);
+        self
+    }
+
+    pub fn aggressive(mut self, enabled: bool) -> Self {
+        self.options.aggressive = enabled;
+        self
+    }
+
+    pub fn collapse_duplicates(mut self, enabled: bool) -> Self {
+        self.options.collapse_duplicates = enabled;
+        self
+    }
+
+    pub fn ocr_tolerant(mut self, enabled: bool) -> Self {
+        self.options.ocr_tolerant = enabled;
+        self
+    }
+
+    pub fn anchors(mut self, enabled: bool) -> Self {
+        self.options.anchors = enabled;
+        self
+    }
+
+    pub fn memo_window(mut self, chars: usize) -> Self {
+        self.options.memo_window = Some(chars);
+        self
+    }
+
+    pub fn pii_window(mut self, chars: usize) -> Self {
+        self.options.pii_window = Some(chars);
+        self
+    }
+
+    pub fn hex64_chain(mut self, chain: &str) -> Self {
+        self.options.hex64_chain = Some(chain.to_string());
+        self
+    }
+
+    pub fn starknet(mut self, enabled: bool) -> Self {
+        self.options.starknet = enabled;
+        self
+    }
+
+    pub fn max_matches(mut self, max: usize) -> Self {
+        self.options.max_matches = Some(max);
+        self
+    }
+
+    pub fn group_positions(mut self, enabled: bool) -> Self {
+        self.options.group_positions = enabled;
+        self
+    }
+
+    /// Reports matches of `type_` under the host's own name `mapped`.
+    pub fn map_type(mut self, type_: &str, mapped: &str) -> Self {
+        self.options
+            .type_map
+            .insert(type_.to_string(), mapped.to_string());
+        self
+    }
+
+    pub fn regex_size_limit(mut self, bytes: usize) -> Self {
+        self.options.regex_size_limit = Some(bytes);
+        self
+    }
+
+    pub fn dfa_size_limit(mut self, bytes: usize) -> Self {
+        self.options.dfa_size_limit = Some(bytes);
+        self
+    }
+
+    /// Compiles the pattern set under the configured limits, so scans on the
+    /// returned detector cannot fail.
+    pub fn build(self) -> Result<Detector, DetectorError> {
+        let limited = limited_regexes(&self.options)?;
+        Ok(Detector {
+            options: self.options,
+            limited,
+            cache: DashMap::new(),
+            cache_capacity: self.cache_capacity,
+            seen: DashSet::new(),
+        })
+    }
+}
+
+/// A configured detector. Build one with `DetectorBuilder` and reuse it.
+/// Its compiled patterns are held directly and its result cache is a
+/// sharded map, so concurrent scans do not contend on a single lock.
+pub struct Detector {
+    options: DetectorOptions,
+    limited: Option<Arc<[Regex]>>,
+    /// Results by text, with the rules generation they were computed under.
+    cache: DashMap<String, (u64, Arc<Capped>)>,
+    cache_capacity: usize,
+    /// Compare keys of the addresses encountered this session.
+    seen: DashSet<String>,
+}
+
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Detector>();
+};
+
+impl Detector {
+    pub fn builder() -> DetectorBuilder {
+        DetectorBuilder::new()
+    }
+
+    pub fn options(&self) -> &DetectorOptions {
+        &self.options
+    }
+
+    /// The cached result for `text`, unless rules were loaded or cleared
+    /// since it was computed.
+    fn cached(&self, text: &str, generation: u64) -> Option<Arc<Capped>> {
+        let hit = self.cache.get(text)?;
+        (hit.0 == generation).then(|| hit.1.clone())
+    }
+
+    /// Applies `group_positions` and `max_matches` to a finished scan and
+    /// caches the result under the rules generation the scan started in.
+    fn remember(&self, text: &str, generation: u64, matches: Vec<crate::Match>) -> Arc<Capped> {
+        let capped = Arc::new(group_and_cap(matches, &self.options));
+        if self.cache_capacity > 0 && text.len() <= MAX_CACHED_TEXT {
+            if self.cache.len() >= self.cache_capacity {
+                self.cache.clear();
+            }
+            self.cache
+                .insert(text.to_string(), (generation, capped.clone()));
+        }
+        capped
+    }
+
+    /// Owned matches for `text`, from the cache when it has been seen before
+    /// under the current rules.
+    fn matches_for(&self, text: &str) -> Arc<Capped> {
+        let generation = crate::rules::generation();
+        if let Some(hit) = self.cached(text, generation) {
+            return hit;
+        }
+        self.remember(
+            text,
+            generation,
+            collect_with(text, &self.options, self.limited.as_deref()),
+        )
+    }
+
+    /// Records an address as seen, returning whether it was new.
+    fn first_sighting(&self, m: &crate::Match) -> bool {
+        if m.kind != "address" {
+            return false;
+        }
+        self.seen.insert(m.compare_key.clone())
+    }
+
+    fn borrow_all<'t>(
+        &self,
+        text: &'t str,
+        capped: Arc<Capped>,
+    ) -> impl Iterator<Item = Match<'t>> {
+        (0..capped.matches.len()).map(move |i| {
+            let m = &capped.matches[i];
+            let first_seen = self.first_sighting(m);
+            Match {
+                first_seen,
+                ..Match::borrow_from(text, m.clone())
+            }
+        })
+    }
+
+    fn collect_result<'t>(&self, text: &'t str, capped: Arc<Capped>) -> ScanResult<'t> {
+        ScanResult {
+            dropped: capped.dropped.clone(),
+            matches: self.borrow_all(text, capped).collect(),
+        }
+    }
+
+    /// Scans `text`, yielding matches in text order with values borrowed from
+    /// it. Addresses are marked `first_seen` as they are yielded. What
+    /// `max_matches` dropped is reported by `scan_all`.
+    pub fn scan<'t>(&self, text: &'t str) -> impl Iterator<Item = Match<'t>> {
+        self.borrow_all(text, self.matches_for(text))
+    }
+
+    /// Forgets every address seen so far, starting a new `first_seen` session.
+    pub fn reset_session(&self) {
+        self.seen.clear();
+    }
+
+    /// Like `scan_all`, but yields to the executor after each pattern family
+    /// so a multi-megabyte body does not starve other tasks on the runtime.
+    /// Works with any executor; it only relies on waking its own task.
+    #[cfg(feature = "async")]
+    pub async fn scan_async<'t>(&self, text: &'t str) -> ScanResult<'t> {
+        let generation = crate::rules::generation();
+        if let Some(hit) = self.cached(text, generation) {
+            return self.collect_result(text, hit);
+        }
+
+        // The same steps as `collect_with`, yielding between patterns.
+        let prepared = Prepared::new(text, &self.options);
+        let scanned = prepared.scanned(text);
+        let mut pipeline = Pipeline::new(scanned, &self.options, self.limited.as_deref());
+        while pipeline.step() {
+            YieldNow(false).await;
+        }
+        let matches = prepared.restore(text, pipeline.finish(), &self.options);
+        self.collect_result(text, self.remember(text, generation, matches))
+    }
+
+    /// Like `scan`, collected into a `ScanResult`.
+    pub fn scan_all<'t>(&self, text: &'t str) -> ScanResult<'t> {
+        self.collect_result(text, self.matches_for(text))
+    }
+}
@@ -0,0 +1,22 @@
+use serde::Serialize;
+use std::fmt;
+
+/// Errors surfaced to hosts instead of panicking inside the detector.
+#[derive(Debug, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum DetectorError {
+    /// A pattern could not be compiled within the configured size limits.
+    PatternTooLarge { rule_id: String, message: String },
+}
+
+impl fmt::Display for DetectorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DetectorError::PatternTooLarge { rule_id, message } => {
+                write!(f, "pattern {rule_id} exceeds the size limit: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DetectorError {}
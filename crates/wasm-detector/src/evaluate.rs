@@ -0,0 +1,98 @@
+use crate::{Match, collect_matches};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// A fixture text together with the matches a correct detector should report.
+#[derive(Deserialize)]
+pub struct LabeledSample {
+    pub text: String,
+    #[serde(default)]
+    pub expected: Vec<ExpectedMatch>,
+}
+
+#[derive(Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ExpectedMatch {
+    pub value: String,
+    pub index: usize,
+    pub type_: String,
+}
+
+#[derive(Serialize, Default)]
+pub struct TypeScore {
+    pub true_positives: usize,
+    pub false_positives: usize,
+    pub false_negatives: usize,
+    pub precision: f64,
+    pub recall: f64,
+}
+
+impl TypeScore {
+    fn finish(&mut self) {
+        let detected = self.true_positives + self.false_positives;
+        let expected = self.true_positives + self.false_negatives;
+        self.precision = ratio(self.true_positives, detected);
+        self.recall = ratio(self.true_positives, expected);
+    }
+}
+
+#[derive(Serialize, Default)]
+pub struct EvaluationReport {
+    pub per_type: BTreeMap<String, TypeScore>,
+    pub overall: TypeScore,
+}
+
+/// An empty denominator means nothing could go wrong, so it scores as perfect.
+fn ratio(hits: usize, total: usize) -> f64 {
+    if total == 0 {
+        1.0
+    } else {
+        hits as f64 / total as f64
+    }
+}
+
+/// Scores the built-in detectors against a labeled corpus.
+pub fn evaluate(corpus: &[LabeledSample]) -> EvaluationReport {
+    evaluate_with(corpus, collect_matches)
+}
+
+/// Scores an arbitrary detection function against a labeled corpus, reporting
+/// precision and recall per match type. A match counts as correct only when
+/// value, index and type all agree with a labeled entry.
+pub fn evaluate_with<F>(corpus: &[LabeledSample], detect: F) -> EvaluationReport
+where
+    F: Fn(&str) -> Vec<Match>,
+{
+    let mut report = EvaluationReport::default();
+
+    for sample in corpus {
+        let expected: BTreeSet<(&str, usize, &str)> = sample
+            .expected
+            .iter()
+            .map(|e| (e.type_.as_str(), e.index, e.value.as_str()))
+            .collect();
+        let detected_matches = detect(&sample.text);
+        let detected: BTreeSet<(&str, usize, &str)> = detected_matches
+            .iter()
+            .map(|m| (m.type_.as_str(), m.index, m.value.as_str()))
+            .collect();
+
+        for key in detected.union(&expected) {
+            let score = report.per_type.entry(key.0.to_string()).or_default();
+            match (detected.contains(key), expected.contains(key)) {
+                (true, true) => score.true_positives += 1,
+                (true, false) => score.false_positives += 1,
+                _ => score.false_negatives += 1,
+            }
+        }
+    }
+
+    for score in report.per_type.values_mut() {
+        report.overall.true_positives += score.true_positives;
+        report.overall.false_positives += score.false_positives;
+        report.overall.false_negatives += score.false_negatives;
+        score.finish();
+    }
+    report.overall.finish();
+
+    report
+}
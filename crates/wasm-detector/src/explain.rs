@@ -0,0 +1,265 @@
+use crate::tokenize::{candidates, tokenize};
+use crate::validators::run_validators;
+use crate::{
+    DetectorError, DetectorOptions, Match, PATTERNS, Pipeline, check_candidate, limited_regexes,
+    regex_for,
+};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Why a regex hit did not become a match.
+#[derive(Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Rejection {
+    /// The pattern's validator refused the value.
+    FailedValidation,
+    /// A higher-priority match already claimed part of the span.
+    Overlapped {
+        type_: String,
+        rule_id: String,
+        index: usize,
+    },
+    /// The pattern is switched off by the options in effect.
+    Disabled,
+    /// A registered `MatchValidator` refused the match.
+    Vetoed { validator: String },
+    /// A whole-text pass after the regex stage removed the match, e.g. a
+    /// context gate that found no chain word nearby.
+    Dropped { pass: String },
+}
+
+/// The state of a match after a whole-text pass changed it: relabeled
+/// (`move_address`, `icp`), extended (`memo`) or, for matches a pass added
+/// (`pii`, `ocr`), created.
+#[derive(Serialize)]
+pub struct PassChange {
+    pub pass: String,
+    pub value: String,
+    pub type_: String,
+    pub rule_id: String,
+}
+
+/// One raw regex hit and the detector's verdict on it.
+#[derive(Serialize)]
+pub struct Explanation {
+    pub value: String,
+    pub index: usize,
+    pub type_: String,
+    pub rule_id: String,
+    pub accepted: bool,
+    pub rejection: Option<Rejection>,
+    /// Changes made by passes after the regex stage, in order; the last one
+    /// is what the scan reports.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub passes: Vec<PassChange>,
+}
+
+// --- Pass Tracing ---
+
+#[derive(Clone, PartialEq)]
+struct Snapshot {
+    value: String,
+    type_: String,
+    rule_id: String,
+}
+
+/// One match a pass changed, keyed by its start.
+struct PassEvent {
+    pass: &'static str,
+    index: usize,
+    before: Option<Snapshot>,
+    after: Option<Snapshot>,
+}
+
+/// Records, for `explain`, how each whole-text pass of `Pipeline::finish`
+/// changed the matches, by comparing them before and after the pass.
+pub(crate) struct PassTrace {
+    current: BTreeMap<usize, Snapshot>,
+    events: Vec<PassEvent>,
+}
+
+fn snapshot(matches: &[Match]) -> BTreeMap<usize, Snapshot> {
+    let mut by_index = BTreeMap::new();
+    for m in matches {
+        by_index.entry(m.index).or_insert_with(|| Snapshot {
+            value: m.value.clone(),
+            type_: m.type_.clone(),
+            rule_id: m.rule_id.clone(),
+        });
+    }
+    by_index
+}
+
+impl PassTrace {
+    fn new(matches: &[Match]) -> Self {
+        PassTrace {
+            current: snapshot(matches),
+            events: Vec::new(),
+        }
+    }
+
+    fn pass(&mut self, pass: &'static str, matches: &[Match]) {
+        let after = snapshot(matches);
+        for (&index, before) in &self.current {
+            let now = after.get(&index);
+            if now != Some(before) {
+                self.events.push(PassEvent {
+                    pass,
+                    index,
+                    before: Some(before.clone()),
+                    after: now.cloned(),
+                });
+            }
+        }
+        for (&index, added) in &after {
+            if !self.current.contains_key(&index) {
+                self.events.push(PassEvent {
+                    pass,
+                    index,
+                    before: None,
+                    after: Some(added.clone()),
+                });
+            }
+        }
+        self.current = after;
+    }
+}
+
+/// Records what the pass named `pass` did, when tracing.
+pub(crate) fn record(trace: &mut Option<PassTrace>, pass: &'static str, matches: &[Match]) {
+    if let Some(trace) = trace {
+        trace.pass(pass, matches);
+    }
+}
+
+fn change(pass: &str, snapshot: Snapshot) -> PassChange {
+    PassChange {
+        pass: pass.to_string(),
+        value: snapshot.value,
+        type_: snapshot.type_,
+        rule_id: snapshot.rule_id,
+    }
+}
+
+/// Applies the traced passes to the regex-stage verdicts: accepted matches
+/// a pass removed become `Dropped`, changes are listed in `passes`, and
+/// matches a pass added are appended as accepted.
+fn apply_trace(explanations: &mut Vec<Explanation>, trace: PassTrace) {
+    let mut state: BTreeMap<usize, (usize, Snapshot)> = explanations
+        .iter()
+        .enumerate()
+        .filter(|(_, e)| e.accepted)
+        .map(|(i, e)| {
+            let snapshot = Snapshot {
+                value: e.value.clone(),
+                type_: e.type_.clone(),
+                rule_id: e.rule_id.clone(),
+            };
+            (e.index, (i, snapshot))
+        })
+        .collect();
+
+    for event in trace.events {
+        match (event.before, event.after) {
+            (Some(before), after) => {
+                let Some((i, current)) = state.get_mut(&event.index) else {
+                    continue;
+                };
+                if *current != before {
+                    continue;
+                }
+                let explanation = &mut explanations[*i];
+                match after {
+                    Some(after) => {
+                        *current = after.clone();
+                        explanation.passes.push(change(event.pass, after));
+                    }
+                    None => {
+                        explanation.accepted = false;
+                        explanation.rejection = Some(Rejection::Dropped {
+                            pass: event.pass.to_string(),
+                        });
+                        state.remove(&event.index);
+                    }
+                }
+            }
+            (None, Some(added)) => {
+                state.insert(event.index, (explanations.len(), added.clone()));
+                explanations.push(Explanation {
+                    value: added.value.clone(),
+                    index: event.index,
+                    type_: added.type_.clone(),
+                    rule_id: added.rule_id.clone(),
+                    accepted: true,
+                    rejection: None,
+                    passes: vec![change(event.pass, added)],
+                });
+            }
+            (None, None) => {}
+        }
+    }
+}
+
+/// Replays detection and returns every candidate, accepted or not, in the
+/// order the patterns were evaluated, then runs the same whole-text passes
+/// as a scan and records what each dropped, relabeled or added. Candidates
+/// of disabled patterns are listed too so "why wasn't this matched" has an
+/// answer.
+pub fn explain(text: &str, options: &DetectorOptions) -> Result<Vec<Explanation>, DetectorError> {
+    let limited = limited_regexes(options)?;
+    let mut matches: Vec<Match> = Vec::new();
+    let mut explanations = Vec::new();
+
+    let tokens = tokenize(text);
+    let active = options.prioritize(PATTERNS);
+    let disabled = PATTERNS
+        .iter()
+        .filter(|p| !active.iter().any(|a| a.rule_id == p.rule_id));
+
+    for pattern in active.iter().copied() {
+        let mut accepted = Vec::new();
+        let re = regex_for(pattern, limited.as_deref());
+        for (start, value) in candidates(text, &tokens, pattern, re) {
+            let verdict = check_candidate(pattern, value, start, &matches).and_then(|()| {
+                let mut m = pattern.to_match(value, start);
+                run_validators(&mut m)?;
+                accepted.push(m);
+                Ok(())
+            });
+            explanations.push(Explanation {
+                value: value.to_string(),
+                index: start,
+                type_: pattern.type_.to_string(),
+                rule_id: pattern.rule_id.to_string(),
+                accepted: verdict.is_ok(),
+                rejection: verdict.err(),
+                passes: Vec::new(),
+            });
+        }
+        matches.extend(accepted);
+    }
+
+    let mut pipeline = Pipeline::new(text, options, limited.as_deref());
+    pipeline.next = pipeline.patterns.len();
+    pipeline.trace = Some(PassTrace::new(&matches));
+    pipeline.matches = matches;
+    if let (_, Some(trace)) = pipeline.finish_traced() {
+        apply_trace(&mut explanations, trace);
+    }
+
+    for pattern in disabled {
+        for cap in pattern.regex().find_iter(text) {
+            explanations.push(Explanation {
+                value: cap.as_str().to_string(),
+                index: cap.start(),
+                type_: pattern.type_.to_string(),
+                rule_id: pattern.rule_id.to_string(),
+                accepted: false,
+                rejection: Some(Rejection::Disabled),
+                passes: Vec::new(),
+            });
+        }
+    }
+
+    Ok(explanations)
+}
@@ -0,0 +1,97 @@
+//! Shared fixture format for checking that every binding behaves the same:
+//! an input, the options to scan it with, and the exact matches expected.
+//! Each binding exposes `run_fixture`, taking the fixture as JSON text so
+//! the same files under `fixtures/` drive all of them.
+//!
+//! ```json
+//! {
+//!   "name": "evm address in prose",
+//!   "input": "pay 0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed now",
+//!   "options": { "disabled": ["ens"] },
+//!   "expected": [
+//!     { "value": "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed", "index": 4,
+//!       "type_": "fullAddress", "rule_id": "evm.address" }
+//!   ]
+//! }
+//! ```
+
+use crate::{DetectorOptions, collect_matches_with_options};
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize)]
+pub struct Fixture {
+    #[serde(default)]
+    pub name: String,
+    pub input: String,
+    #[serde(default)]
+    pub options: DetectorOptions,
+    #[serde(default)]
+    pub expected: Vec<FixtureMatch>,
+}
+
+/// One expected match. `rule_id` is only compared when the fixture gives it.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct FixtureMatch {
+    pub value: String,
+    pub index: usize,
+    pub type_: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rule_id: Option<String>,
+}
+
+impl FixtureMatch {
+    fn agrees_with(&self, other: &FixtureMatch) -> bool {
+        self.value == other.value
+            && self.index == other.index
+            && self.type_ == other.type_
+            && (self.rule_id.is_none() || self.rule_id == other.rule_id)
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct FixtureOutcome {
+    pub name: String,
+    pub passed: bool,
+    /// Expected matches the detector did not report.
+    pub missing: Vec<FixtureMatch>,
+    /// Reported matches the fixture does not list.
+    pub unexpected: Vec<FixtureMatch>,
+}
+
+/// Runs a parsed fixture.
+pub fn check_fixture(fixture: &Fixture) -> Result<FixtureOutcome, String> {
+    let actual: Vec<FixtureMatch> = collect_matches_with_options(&fixture.input, &fixture.options)
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .map(|m| FixtureMatch {
+            value: m.value,
+            index: m.index,
+            type_: m.type_,
+            rule_id: Some(m.rule_id),
+        })
+        .collect();
+
+    let missing: Vec<FixtureMatch> = fixture
+        .expected
+        .iter()
+        .filter(|e| !actual.iter().any(|a| e.agrees_with(a)))
+        .cloned()
+        .collect();
+    let unexpected: Vec<FixtureMatch> = actual
+        .into_iter()
+        .filter(|a| !fixture.expected.iter().any(|e| e.agrees_with(a)))
+        .collect();
+
+    Ok(FixtureOutcome {
+        name: fixture.name.clone(),
+        passed: missing.is_empty() && unexpected.is_empty(),
+        missing,
+        unexpected,
+    })
+}
+
+/// Parses and runs a fixture given as JSON text.
+pub fn run_fixture(json: &str) -> Result<FixtureOutcome, String> {
+    let fixture: Fixture = serde_json::from_str(json).map_err(|e| e.to_string())?;
+    check_fixture(&fixture)
+}
@@ -0,0 +1,61 @@
+//! Display helpers that shorten full values the same way explorers do, kept
+//! within the shapes the truncated patterns recognize so a shortened value
+//! is still detected (and masked) if it is pasted back in.
+
+use crate::grapheme;
+
+/// Separator between the kept head and tail.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum EllipsisStyle {
+    /// `...`
+    #[default]
+    Ascii,
+    /// `…` (U+2026)
+    Unicode,
+}
+
+impl EllipsisStyle {
+    /// Parses `"ascii"` or `"unicode"`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "ascii" => Some(EllipsisStyle::Ascii),
+            "unicode" => Some(EllipsisStyle::Unicode),
+            _ => None,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            EllipsisStyle::Ascii => "...",
+            EllipsisStyle::Unicode => "…",
+        }
+    }
+}
+
+/// Head/tail lengths the truncated patterns accept: 4–12 hex digits after
+/// `0x`, and 4–10 Base58 characters (the range shared by the address and
+/// signature patterns) otherwise.
+fn recognized_range(value: &str) -> (usize, usize) {
+    if value.starts_with("0x") {
+        (4, 12)
+    } else {
+        (4, 10)
+    }
+}
+
+/// Shortens `value` to `head` leading and `tail` trailing grapheme clusters joined
+/// by the chosen ellipsis, e.g. `0x5aAeb6...1BeAed`. A `0x` prefix is kept
+/// and not counted. Lengths are clamped to what the detector recognizes as
+/// truncated, and values too short to shorten are returned unchanged.
+pub fn format_short(value: &str, head: usize, tail: usize, style: EllipsisStyle) -> String {
+    let (prefix, body) = value.split_at(if value.starts_with("0x") { 2 } else { 0 });
+    let (min, max) = recognized_range(value);
+    let (head, tail) = (head.clamp(min, max), tail.clamp(min, max));
+
+    if head + tail >= grapheme::len(body) {
+        return value.to_string();
+    }
+    let start = grapheme::head(body, head);
+    let end = grapheme::tail(body, tail);
+    format!("{prefix}{start}{}{end}", style.as_str())
+}
@@ -0,0 +1,185 @@
+use serde::Serialize;
+
+const HEX: &[u8] = b"0123456789abcdef";
+const BASE58: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const ENS_SYLLABLES: &[&str] = &[
+    "vita", "lik", "nou", "ns", "moon", "dao", "ape", "gm", "wag", "mi", "satoshi", "ledger",
+    "defi", "punk", "chad", "frens", "based", "kai", "zen", "luna",
+];
+const FILLER: &[&str] = &[
+    "please",
+    "send",
+    "the",
+    "funds",
+    "to",
+    "my",
+    "wallet",
+    "at",
+    "thanks",
+    "here",
+    "is",
+    "tx",
+    "confirmed",
+    "pending",
+    "check",
+    "explorer",
+    "for",
+    "details",
+    "refund",
+    "address",
+    "deposit",
+    "from",
+    "signature",
+    "name",
+    "and",
+    "then",
+];
+
+#[derive(Serialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum Family {
+    EthAddress,
+    EthTxHash,
+    SolAddress,
+    SolTxSignature,
+    EnsName,
+}
+
+const FAMILIES: &[Family] = &[
+    Family::EthAddress,
+    Family::EthTxHash,
+    Family::SolAddress,
+    Family::SolTxSignature,
+    Family::EnsName,
+];
+
+#[derive(Serialize)]
+pub struct GeneratedItem {
+    pub value: String,
+    pub index: usize,
+    pub family: Family,
+    pub truncated: bool,
+}
+
+#[derive(Serialize)]
+pub struct GeneratedDocument {
+    pub text: String,
+    pub items: Vec<GeneratedItem>,
+}
+
+/// Deterministic generator of synthetic chain data. The same seed always
+/// produces the same output, so generated corpora are reproducible.
+pub struct Generator {
+    state: u64,
+}
+
+impl Generator {
+    pub fn new(seed: u64) -> Self {
+        Generator { state: seed }
+    }
+
+    /// SplitMix64 step; statistically fine for test data and needs no dependencies.
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    fn between(&mut self, min: usize, max: usize) -> usize {
+        min + self.below(max - min + 1)
+    }
+
+    fn chars_from(&mut self, alphabet: &[u8], len: usize) -> String {
+        (0..len)
+            .map(|_| alphabet[self.below(alphabet.len())] as char)
+            .collect()
+    }
+
+    pub fn eth_address(&mut self) -> String {
+        format!("0x{}", self.chars_from(HEX, 40))
+    }
+
+    pub fn eth_tx_hash(&mut self) -> String {
+        format!("0x{}", self.chars_from(HEX, 64))
+    }
+
+    pub fn sol_address(&mut self) -> String {
+        let len = self.between(43, 44);
+        self.chars_from(BASE58, len)
+    }
+
+    pub fn sol_signature(&mut self) -> String {
+        let len = self.between(87, 88);
+        self.chars_from(BASE58, len)
+    }
+
+    pub fn ens_name(&mut self) -> String {
+        let parts = self.between(1, 3);
+        let label: String = (0..parts)
+            .map(|_| ENS_SYLLABLES[self.below(ENS_SYLLABLES.len())])
+            .collect();
+        format!("{label}.eth")
+    }
+
+    pub fn value(&mut self, family: Family) -> String {
+        match family {
+            Family::EthAddress => self.eth_address(),
+            Family::EthTxHash => self.eth_tx_hash(),
+            Family::SolAddress => self.sol_address(),
+            Family::SolTxSignature => self.sol_signature(),
+            Family::EnsName => self.ens_name(),
+        }
+    }
+
+    /// Shortens a value the way explorers do (`0x1234...abcd` / `7xKX…sAsU`).
+    pub fn truncate(&mut self, value: &str) -> String {
+        let (prefix, body) = value.split_at(if value.starts_with("0x") { 2 } else { 0 });
+        let head = self.between(4, 6);
+        let tail = self.between(4, 6);
+        let ellipsis = if self.below(2) == 0 { "..." } else { "…" };
+        format!(
+            "{prefix}{}{ellipsis}{}",
+            &body[..head],
+            &body[body.len() - tail..]
+        )
+    }
+
+    /// Builds a short prose document with `count` embedded values drawn from every family.
+    pub fn document(&mut self, count: usize) -> GeneratedDocument {
+        let mut text = String::new();
+        let mut items = Vec::with_capacity(count);
+
+        for _ in 0..count {
+            for _ in 0..self.between(2, 6) {
+                text.push_str(FILLER[self.below(FILLER.len())]);
+                text.push(' ');
+            }
+
+            let family = FAMILIES[self.below(FAMILIES.len())];
+            let full = self.value(family);
+            let truncated = family != Family::EnsName && self.below(3) == 0;
+            let value = if truncated {
+                self.truncate(&full)
+            } else {
+                full
+            };
+
+            items.push(GeneratedItem {
+                index: text.len(),
+                value: value.clone(),
+                family,
+                truncated,
+            });
+            text.push_str(&value);
+            text.push_str(if self.below(4) == 0 { ".\n" } else { " " });
+        }
+
+        GeneratedDocument { text, items }
+    }
+}
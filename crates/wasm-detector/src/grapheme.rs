@@ -0,0 +1,42 @@
+//! Text windows measured in grapheme clusters, so context extraction and
+//! shortening never cut through a multi-byte character or a cluster such as
+//! an emoji with a skin-tone modifier or a letter with combining accents.
+
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Number of grapheme clusters in `s`.
+pub fn len(s: &str) -> usize {
+    s.graphemes(true).count()
+}
+
+/// The first `count` grapheme clusters of `s`.
+pub fn head(s: &str, count: usize) -> &str {
+    let end = s
+        .grapheme_indices(true)
+        .nth(count)
+        .map_or(s.len(), |(i, _)| i);
+    &s[..end]
+}
+
+/// The last `count` grapheme clusters of `s`.
+pub fn tail(s: &str, count: usize) -> &str {
+    if count == 0 {
+        return "";
+    }
+    let start = s
+        .grapheme_indices(true)
+        .rev()
+        .nth(count - 1)
+        .map_or(0, |(i, _)| i);
+    &s[start..]
+}
+
+/// Up to `count` grapheme clusters of `text` ending at byte offset `index`.
+pub fn before(text: &str, index: usize, count: usize) -> &str {
+    tail(&text[..index], count)
+}
+
+/// Up to `count` grapheme clusters of `text` starting at byte offset `index`.
+pub fn after(text: &str, index: usize, count: usize) -> &str {
+    head(&text[index..], count)
+}
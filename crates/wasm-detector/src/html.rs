@@ -0,0 +1,207 @@
+//! HTML-aware scanning: text content is scanned without tripping over
+//! markup, and the attributes dApps tend to hide full values in (`href`,
+//! `title`, `data-*`, input `value`) are scanned too, with each match
+//! recording which attribute it came from. Comments and `<meta content>` are
+//! scanned when `DetectorOptions::html_comments`/`html_meta` ask for them.
+//! Indexes are byte offsets into the HTML source.
+
+use crate::{
+    DetectorError, DetectorOptions, Match, collect_with, group_and_cap, is_editable,
+    limited_regexes,
+};
+
+/// Elements whose content is code or styling rather than visible text.
+const RAW_TEXT_ELEMENTS: &[&str] = &["script", "style"];
+
+/// Where in the document a span lies.
+#[derive(Clone, Copy)]
+enum Source<'a> {
+    Text,
+    Comment,
+    /// An attribute value, with its tag and attribute names as written.
+    Attribute(&'a str, &'a str),
+}
+
+/// A region of the source worth scanning.
+struct Span<'a> {
+    start: usize,
+    end: usize,
+    source: Source<'a>,
+}
+
+fn is_scanned_attribute(tag: &str, name: &str, options: &DetectorOptions) -> bool {
+    matches!(name, "href" | "title")
+        || name.starts_with("data-")
+        || (name == "value" && tag == "input")
+        || (name == "content" && tag == "meta" && options.html_meta)
+}
+
+/// Parses one tag starting at `start` (just past `<`), collecting scanned
+/// attribute spans. Returns the tag name (lowercased, with a leading `/` for
+/// closing tags) and the offset just past `>`.
+fn parse_tag<'a>(
+    html: &'a str,
+    start: usize,
+    options: &DetectorOptions,
+    spans: &mut Vec<Span<'a>>,
+) -> (String, usize) {
+    let bytes = html.as_bytes();
+    let name_start = if html[start..].starts_with('/') {
+        start + 1
+    } else {
+        start
+    };
+    let name_end = html[name_start..]
+        .find(|c: char| c.is_ascii_whitespace() || c == '>' || c == '/')
+        .map_or(html.len(), |i| name_start + i);
+    let tag = html[start..name_end].to_ascii_lowercase();
+
+    let mut i = name_end;
+    while i < bytes.len() && bytes[i] != b'>' {
+        if bytes[i].is_ascii_whitespace() || bytes[i] == b'/' {
+            i += 1;
+            continue;
+        }
+        let attr_start = i;
+        while i < bytes.len()
+            && !matches!(bytes[i], b'=' | b'>' | b'/')
+            && !bytes[i].is_ascii_whitespace()
+        {
+            i += 1;
+        }
+        let name = &html[attr_start..i];
+        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+        if i >= bytes.len() || bytes[i] != b'=' {
+            continue;
+        }
+        i += 1;
+        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+
+        let (value_start, value_end) = match bytes.get(i) {
+            Some(&quote @ (b'"' | b'\'')) => {
+                let close = html[i + 1..]
+                    .find(quote as char)
+                    .map_or(html.len(), |j| i + 1 + j);
+                let span = (i + 1, close);
+                i = (close + 1).min(html.len());
+                span
+            }
+            _ => {
+                let value_start = i;
+                while i < bytes.len() && bytes[i] != b'>' && !bytes[i].is_ascii_whitespace() {
+                    i += 1;
+                }
+                (value_start, i)
+            }
+        };
+
+        let lowered = name.to_ascii_lowercase();
+        if is_scanned_attribute(&tag, &lowered, options) {
+            spans.push(Span {
+                start: value_start,
+                end: value_end,
+                source: Source::Attribute(&html[name_start..name_end], name),
+            });
+        }
+    }
+    (tag, (i + 1).min(html.len()))
+}
+
+/// Splits an HTML document into the text, attribute and (optionally)
+/// comment regions to scan.
+fn spans<'a>(html: &'a str, options: &DetectorOptions) -> Vec<Span<'a>> {
+    let mut spans = Vec::new();
+    let mut text_start = 0;
+    let mut i = 0;
+
+    while let Some(offset) = html[i..].find('<') {
+        let lt = i + offset;
+        let next = html[lt + 1..].chars().next();
+        let opens_tag = next.is_some_and(|c| c.is_ascii_alphabetic() || c == '/');
+        let opens_comment = html[lt..].starts_with("<!--");
+        if !opens_tag && !opens_comment {
+            i = lt + 1;
+            continue;
+        }
+
+        if lt > text_start {
+            spans.push(Span {
+                start: text_start,
+                end: lt,
+                source: Source::Text,
+            });
+        }
+
+        if opens_comment {
+            let body = lt + 4;
+            let close = html[body..].find("-->").map_or(html.len(), |j| body + j);
+            if options.html_comments && close > body {
+                spans.push(Span {
+                    start: body,
+                    end: close,
+                    source: Source::Comment,
+                });
+            }
+            i = (close + 3).min(html.len());
+        } else {
+            let (tag, after) = parse_tag(html, lt + 1, options, &mut spans);
+            i = after;
+            if RAW_TEXT_ELEMENTS.contains(&tag.as_str()) {
+                let close = format!("</{tag}");
+                i = html[i..]
+                    .to_ascii_lowercase()
+                    .find(&close)
+                    .map_or(html.len(), |j| i + j);
+            }
+        }
+        text_start = i;
+    }
+
+    if text_start < html.len() {
+        spans.push(Span {
+            start: text_start,
+            end: html.len(),
+            source: Source::Text,
+        });
+    }
+    spans
+}
+
+/// Scans an HTML document's text content and value-bearing attributes.
+/// Every match carries `metadata.source` (`text`, `attribute`, `meta` or
+/// `comment`); attribute matches also carry `metadata.tag` and
+/// `metadata.attribute`. `group_positions` and `max_matches` apply to the
+/// whole document rather than to each span.
+pub fn collect_matches_html(
+    html: &str,
+    options: &DetectorOptions,
+) -> Result<Vec<Match>, DetectorError> {
+    let limited = limited_regexes(options)?;
+    let mut matches = Vec::new();
+    for span in spans(html, options) {
+        let text = &html[span.start..span.end];
+        for mut m in collect_with(text, options, limited.as_deref()) {
+            m.shift(span.start);
+            m.editable = is_editable(&m, &options.editable_ranges);
+            let source = match span.source {
+                Source::Text => "text",
+                Source::Comment => "comment",
+                Source::Attribute(tag, attribute) => {
+                    let tag = tag.to_ascii_lowercase();
+                    let source = if tag == "meta" { "meta" } else { "attribute" };
+                    m.metadata.insert("tag".to_string(), tag);
+                    m.metadata
+                        .insert("attribute".to_string(), attribute.to_ascii_lowercase());
+                    source
+                }
+            };
+            m.metadata.insert("source".to_string(), source.to_string());
+            matches.push(m);
+        }
+    }
+    Ok(group_and_cap(matches, options).matches)
+}
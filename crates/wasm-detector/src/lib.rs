@@ -1,229 +1,694 @@
-use regex::Regex;
+pub mod address_book;
+pub mod aggregate;
+mod aggressive;
+pub mod anchor;
+pub mod annotate;
+#[cfg(feature = "audit")]
+pub mod audit;
+mod baseline;
+pub mod batch;
+mod bidi;
+#[cfg(feature = "bindgen")]
+mod bindings;
+pub mod bytes;
+pub mod cap;
+#[cfg(feature = "evm")]
+pub mod chain_registry;
+mod chains;
+pub mod chunked;
+pub mod compare;
+pub mod confusion;
+pub mod detector;
+mod error;
+pub mod evaluate;
+pub mod explain;
+pub mod fixture;
+pub mod format;
+pub mod generate;
+pub mod grapheme;
+pub mod html;
+pub mod mask;
+mod memo;
+#[cfg(feature = "mmap")]
+pub mod mmap;
+#[cfg(feature = "evm")]
+mod move_address;
+mod ocr;
+mod options;
+mod pii;
+pub mod profiles;
+mod reflow;
+pub mod report;
+pub mod rules;
+pub mod stream;
+mod taxonomy;
+#[cfg(feature = "metrics")]
+pub mod telemetry;
+mod tokenize;
+pub mod validators;
+
+pub use baseline::Baseline;
+#[cfg(feature = "bech32")]
+pub use chains::bech32;
+pub use chains::{PATTERNS, enabled_features};
+pub use detector::{Detector, DetectorBuilder, ScanResult};
+pub use error::DetectorError;
+use explain::Rejection;
+pub use options::{DetectorOptions, default_options, options_schema};
+use regex::{Regex, RegexBuilder};
 use serde::Serialize;
-use std::sync::OnceLock;
-use wasm_bindgen::prelude::*;
+use std::collections::BTreeMap;
+use std::ops::Range;
+use std::sync::{Arc, Mutex, OnceLock};
 
-#[derive(Serialize)]
+#[derive(Serialize, Clone)]
 pub struct Match {
+    /// The matched text exactly as written.
     pub value: String,
     pub index: usize,
     pub type_: String,
+    pub rule_id: String,
+    /// Normalized form of the value (see `compare::compare_key`) for
+    /// allowlist checks and dedup; equal keys mean the same value.
+    pub compare_key: String,
+    /// Extra data attached by registered `MatchValidator`s.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub metadata: BTreeMap<String, String>,
+    /// Every chain whose pattern matched this exact string, when duplicate
+    /// collapsing is enabled and more than one did.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub possible_chains: Vec<String>,
+    /// Set when OCR-tolerant mode repaired the value; `value` stays the raw
+    /// text on the page and `corrected_value` holds the checksum-valid form.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub corrected: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub corrected_value: Option<String>,
+    /// Set when the value overlaps one of `DetectorOptions::editable_ranges`,
+    /// i.e. text the user may still be typing.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub editable: bool,
+    /// Byte offsets of every occurrence of this value and type, set only
+    /// when `DetectorOptions::group_positions` folds repeats into one match.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub positions: Vec<u32>,
+    /// Which network of its chain the value belongs to (`mainnet`,
+    /// `testnet`, `regtest`, ...), when the format encodes it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+    /// The value's canonical equivalent on a related chain (e.g. the `0x`
+    /// form of a Harmony `one1…` address), set when `DetectorOptions::normalize` is on.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub normalized: Option<String>,
+    /// The chain family (`ethereum`, `bitcoin`, `solana`, ...), or a Cosmos
+    /// chain named by its HRP. Unset where the chain is unknown (PII, Move
+    /// addresses naming neither Aptos nor Sui). Named `chain` because
+    /// `network` already holds the network within it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chain: Option<String>,
+    /// What the value is: `address`, `tx`, `name`, `chain_id`,
+    /// `inscription`, `link`, `pii`, or `ambiguous` for truncated values
+    /// that may be either of two.
+    pub kind: String,
+    /// Which form of its kind the value takes (`legacy`, `segwit`,
+    /// `bech32`, `implicit`, ...), or `truncated` for shortened values.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variant: Option<String>,
 }
 
-// --- Static Regex Definitions ---
-
-static FULL_ADDRESS_RE: OnceLock<Regex> = OnceLock::new();
-static TRUNCATED_RE: OnceLock<Regex> = OnceLock::new();
-static ENS_RE: OnceLock<Regex> = OnceLock::new();
-static SOL_RE: OnceLock<Regex> = OnceLock::new();
-static SOL_TRUNCATED_RE: OnceLock<Regex> = OnceLock::new();
-
-// Transaction hash regex patterns
-static ETH_TX_HASH_RE: OnceLock<Regex> = OnceLock::new();
-static ETH_TX_TRUNCATED_RE: OnceLock<Regex> = OnceLock::new();
-static SOL_TX_SIG_RE: OnceLock<Regex> = OnceLock::new();
-static SOL_TX_TRUNCATED_RE: OnceLock<Regex> = OnceLock::new();
+impl Match {
+    /// Every byte offset this match stands for: its `positions` when
+    /// grouped, otherwise just `index`.
+    pub fn starts(&self) -> Vec<usize> {
+        if self.positions.is_empty() {
+            vec![self.index]
+        } else {
+            self.positions.iter().map(|&p| p as usize).collect()
+        }
+    }
 
-fn get_full_address_re() -> &'static Regex {
-    FULL_ADDRESS_RE.get_or_init(|| Regex::new(r"\b0x[a-fA-F0-9]{40}\b").unwrap())
+    /// Moves the match (and any grouped positions) `by` bytes later, for
+    /// callers that scan a slice of a larger text.
+    pub(crate) fn shift(&mut self, by: usize) {
+        self.index += by;
+        for p in &mut self.positions {
+            *p += by as u32;
+        }
+    }
 }
 
-fn get_truncated_re() -> &'static Regex {
-    TRUNCATED_RE
-        .get_or_init(|| Regex::new(r"\b0x[a-fA-F0-9]{4,12}(?:\.{3}|…)[a-fA-F0-9]{4,12}\b").unwrap())
+// --- Priority Table ---
+
+/// A detection rule: the regex that finds candidates, an optional validator
+/// that rejects false positives, and the match type it emits. `rule_id` is a
+/// stable identifier that stays fixed across releases even if the type or
+/// regex changes, so reports and suppressions can reference it.
+pub struct Pattern {
+    pub type_: &'static str,
+    pub rule_id: &'static str,
+    pub chain: &'static str,
+    pub source: &'static str,
+    regex: fn() -> &'static Regex,
+    validate: Option<fn(&str) -> bool>,
 }
 
-fn get_ens_re() -> &'static Regex {
-    ENS_RE.get_or_init(|| Regex::new(r"(?i)\b[a-z0-9](?:[a-z0-9-]{0,61}[a-z0-9])?\.eth\b").unwrap())
-}
+impl Pattern {
+    pub fn regex(&self) -> &'static Regex {
+        (self.regex)()
+    }
 
-fn get_sol_re() -> &'static Regex {
-    SOL_RE.get_or_init(|| Regex::new(r"\b[1-9A-HJ-NP-Za-km-z]{32,44}\b").unwrap())
-}
+    /// Position of this pattern in `PATTERNS`, used to index parallel tables.
+    fn position(&self) -> usize {
+        PATTERNS.iter().position(|p| std::ptr::eq(p, self)).unwrap()
+    }
 
-fn get_sol_truncated_re() -> &'static Regex {
-    SOL_TRUNCATED_RE.get_or_init(|| {
-        Regex::new(r"\b[1-9A-HJ-NP-Za-km-z]{3,10}(?:\.{3}|…)[1-9A-HJ-NP-Za-km-z]{3,10}\b").unwrap()
-    })
+    pub fn accepts(&self, value: &str) -> bool {
+        self.validate.is_none_or(|validate| validate(value))
+    }
+
+    fn to_match(&self, value: &str, index: usize) -> Match {
+        Match {
+            value: value.to_string(),
+            index,
+            type_: self.type_.to_string(),
+            rule_id: self.rule_id.to_string(),
+            compare_key: String::new(),
+            metadata: BTreeMap::new(),
+            possible_chains: Vec::new(),
+            corrected: false,
+            corrected_value: None,
+            editable: false,
+            positions: Vec::new(),
+            network: None,
+            normalized: None,
+            chain: None,
+            kind: String::new(),
+            variant: None,
+        }
+    }
 }
 
-// --- Transaction Hash Regex Getters ---
+// --- Size-Limited Pattern Sets ---
 
-fn get_eth_tx_hash_re() -> &'static Regex {
-    ETH_TX_HASH_RE.get_or_init(|| Regex::new(r"\b0x[a-fA-F0-9]{64}\b").unwrap())
-}
+type Limits = (Option<usize>, Option<usize>);
+type LimitedSet = (Limits, Arc<[Regex]>);
 
-fn get_eth_tx_truncated_re() -> &'static Regex {
-    ETH_TX_TRUNCATED_RE
-        .get_or_init(|| Regex::new(r"\b0x[a-fA-F0-9]{4,12}(?:\.{3}|…)[a-fA-F0-9]{4,12}\b").unwrap())
-}
+static LIMITED_SETS: OnceLock<Mutex<Vec<LimitedSet>>> = OnceLock::new();
+
+/// Every pattern compiled under the options' program and lazy-DFA size
+/// limits, or `None` when no limit is set and the shared statics apply.
+/// Sets are cached per distinct limit pair.
+fn limited_regexes(options: &DetectorOptions) -> Result<Option<Arc<[Regex]>>, DetectorError> {
+    let limits = (options.regex_size_limit, options.dfa_size_limit);
+    if limits == (None, None) {
+        return Ok(None);
+    }
+
+    let mut sets = LIMITED_SETS
+        .get_or_init(|| Mutex::new(Vec::new()))
+        .lock()
+        .unwrap();
+    if let Some((_, set)) = sets.iter().find(|(l, _)| *l == limits) {
+        return Ok(Some(set.clone()));
+    }
 
-fn get_sol_tx_sig_re() -> &'static Regex {
-    SOL_TX_SIG_RE.get_or_init(|| Regex::new(r"\b[1-9A-HJ-NP-Za-km-z]{86,88}\b").unwrap())
+    let set = PATTERNS
+        .iter()
+        .map(|pattern| {
+            let mut builder = RegexBuilder::new(pattern.source);
+            if let Some(limit) = options.regex_size_limit {
+                builder.size_limit(limit);
+            }
+            if let Some(limit) = options.dfa_size_limit {
+                builder.dfa_size_limit(limit);
+            }
+            builder.build().map_err(|e| DetectorError::PatternTooLarge {
+                rule_id: pattern.rule_id.to_string(),
+                message: e.to_string(),
+            })
+        })
+        .collect::<Result<Arc<[Regex]>, _>>()?;
+
+    sets.push((limits, set.clone()));
+    Ok(Some(set))
 }
 
-fn get_sol_tx_truncated_re() -> &'static Regex {
-    SOL_TX_TRUNCATED_RE.get_or_init(|| {
-        Regex::new(r"\b[1-9A-HJ-NP-Za-km-z]{4,12}(?:\.{3}|…)[1-9A-HJ-NP-Za-km-z]{4,12}\b").unwrap()
-    })
+/// Picks the size-limited regex for a pattern when a limited set is in effect.
+fn regex_for<'a>(pattern: &'static Pattern, limited: Option<&'a [Regex]>) -> &'a Regex {
+    match limited {
+        Some(set) => &set[pattern.position()],
+        None => pattern.regex(),
+    }
 }
 
 // --- Helper Functions ---
 
-/// Checks if a given range [start, end) overlaps with any match in the provided list.
-fn has_overlap(start: usize, end: usize, matches: &[Match]) -> bool {
-    matches.iter().any(|m| {
+/// Returns the first match in the list that overlaps the range [start, end).
+fn find_overlap(start: usize, end: usize, matches: &[Match]) -> Option<&Match> {
+    matches.iter().find(|m| {
         let m_end = m.index + m.value.len();
         (start >= m.index && start < m_end) || (m.index >= start && m.index < end)
     })
 }
 
-/// Scans text with a regex and collects non-overlapping matches.
-fn scan_regex(text: &str, re: &Regex, type_: &str, checks: &[&[Match]]) -> Vec<Match> {
-    let mut results = Vec::new();
-    for cap in re.find_iter(text) {
-        let start = cap.start();
-        let end = cap.end();
+/// Checks if a given range [start, end) overlaps with any match in the provided list.
+fn has_overlap(start: usize, end: usize, matches: &[Match]) -> bool {
+    find_overlap(start, end, matches).is_some()
+}
+
+/// Decides whether a regex hit becomes a match, or why it is rejected.
+fn check_candidate(
+    pattern: &Pattern,
+    value: &str,
+    start: usize,
+    existing_matches: &[Match],
+) -> Result<(), Rejection> {
+    if !pattern.accepts(value) {
+        return Err(Rejection::FailedValidation);
+    }
+    if let Some(m) = find_overlap(start, start + value.len(), existing_matches) {
+        return Err(Rejection::Overlapped {
+            type_: m.type_.clone(),
+            rule_id: m.rule_id.clone(),
+            index: m.index,
+        });
+    }
+    Ok(())
+}
 
-        let overlaps = checks.iter().any(|list| has_overlap(start, end, list));
+/// Scans text with a pattern and collects validated, non-overlapping matches.
+fn scan_pattern(
+    text: &str,
+    tokens: &[Range<usize>],
+    pattern: &Pattern,
+    re: &Regex,
+    existing_matches: &[Match],
+) -> Vec<Match> {
+    let mut results = Vec::new();
+    for (start, value) in tokenize::candidates(text, tokens, pattern, re) {
+        if check_candidate(pattern, value, start, existing_matches).is_err() {
+            continue;
+        }
 
-        if !overlaps {
-            results.push(Match {
-                value: cap.as_str().to_string(),
-                index: start,
-                type_: type_.to_string(),
-            });
+        let mut m = pattern.to_match(value, start);
+        if validators::run_validators(&mut m).is_ok() {
+            results.push(m);
         }
     }
     results
 }
 
-fn is_valid_ens(text: &str) -> bool {
-    if !text.ends_with(".eth") {
-        return false;
+/// Records, on each match, the other chains whose patterns hit the exact same
+/// string, so ambiguous values carry every plausible chain instead of only
+/// the first-wins type.
+fn collapse_duplicates(
+    text: &str,
+    tokens: &[Range<usize>],
+    patterns: &[&'static Pattern],
+    limited: Option<&[Regex]>,
+    matches: &mut [Match],
+) {
+    for pattern in patterns {
+        let re = regex_for(pattern, limited);
+        for (start, value) in tokenize::candidates(text, tokens, pattern, re) {
+            if !pattern.accepts(value) {
+                continue;
+            }
+            let Some(m) = matches
+                .iter_mut()
+                .find(|m| m.index == start && m.value == value)
+            else {
+                continue;
+            };
+            let chain = pattern.chain.to_string();
+            if m.possible_chains.contains(&chain) {
+                continue;
+            }
+            if m.possible_chains.is_empty() {
+                let own_chain = PATTERNS.iter().find(|p| p.rule_id == m.rule_id);
+                let own_chain = own_chain.map_or(pattern.chain, |p| p.chain);
+                if own_chain == pattern.chain {
+                    continue;
+                }
+                m.possible_chains.push(own_chain.to_string());
+            }
+            m.possible_chains.push(chain);
+        }
     }
-    if text.len() <= 7 {
-        return text.len() > 4;
+}
+
+/// Folds repeated (value, type) matches into the first occurrence, which
+/// lists every occurrence's offset in `positions`. Order is kept.
+fn group_positions(matches: Vec<Match>) -> Vec<Match> {
+    let mut grouped: Vec<Match> = Vec::new();
+    let mut first: BTreeMap<(String, String), usize> = BTreeMap::new();
+    for m in matches {
+        let key = (m.value.clone(), m.type_.clone());
+        match first.get(&key) {
+            Some(&i) => grouped[i].positions.push(m.index as u32),
+            None => {
+                first.insert(key, grouped.len());
+                let positions = vec![m.index as u32];
+                grouped.push(Match { positions, ..m });
+            }
+        }
     }
-    true
+    grouped
 }
 
-// --- Detection Logic ---
+/// Whether `m` overlaps any of the host's editable `[start, end)` ranges.
+fn is_editable(m: &Match, ranges: &[[usize; 2]]) -> bool {
+    let end = m.index + m.value.len();
+    ranges
+        .iter()
+        .any(|&[start, stop]| m.index < stop && start < end)
+}
 
-// Transaction hash detection (must run before address detection for proper overlap handling)
+// --- Public API ---
 
-fn find_eth_tx_hashes(text: &str) -> Vec<Match> {
-    scan_regex(text, get_eth_tx_hash_re(), "eth_tx_hash", &[])
+/// Compiles only the EVM patterns up front; every other family stays lazy until first use.
+pub fn precompile_evm_patterns() {
+    for pattern in PATTERNS.iter().filter(|p| p.chain == "ethereum") {
+        pattern.regex();
+    }
 }
 
-fn find_eth_tx_truncated(text: &str, existing_matches: &[Match]) -> Vec<Match> {
-    scan_regex(
-        text,
-        get_eth_tx_truncated_re(),
-        "eth_tx_truncated",
-        &[existing_matches],
-    )
+/// Runs every detector over the text and returns the matches sorted by position.
+pub fn collect_matches(text: &str) -> Vec<Match> {
+    collect_matches_with_options(text, &DetectorOptions::default())
+        .expect("default options set no size limits")
 }
 
-fn find_sol_tx_signatures(text: &str, existing_matches: &[Match]) -> Vec<Match> {
-    let mut matches = Vec::new();
+/// Like `collect_matches`, with the priority order and regex size limits
+/// taken from `options`. Fails if a pattern does not fit within the limits.
+pub fn collect_matches_with_options(
+    text: &str,
+    options: &DetectorOptions,
+) -> Result<Vec<Match>, DetectorError> {
+    Ok(collect_matches_capped(text, options)?.matches)
+}
+
+/// Like `collect_matches_with_options`, also reporting what
+/// `DetectorOptions::max_matches` dropped.
+pub fn collect_matches_capped(
+    text: &str,
+    options: &DetectorOptions,
+) -> Result<cap::Capped, DetectorError> {
+    let limited = limited_regexes(options)?;
+    let matches = collect_with(text, options, limited.as_deref());
+    Ok(group_and_cap(matches, options))
+}
 
-    let full = scan_regex(text, get_sol_tx_sig_re(), "sol_tx_sig", &[existing_matches]);
-    matches.extend(full);
+/// Applies `group_positions` and `max_matches` to a finished scan.
+fn group_and_cap(mut matches: Vec<Match>, options: &DetectorOptions) -> cap::Capped {
+    if options.group_positions {
+        matches = group_positions(matches);
+    }
+    cap::cap_matches(matches, options.max_matches.unwrap_or(usize::MAX))
+}
 
-    let truncated = scan_regex(
-        text,
-        get_sol_tx_truncated_re(),
-        "sol_tx_truncated",
-        &[existing_matches, &matches],
-    );
-    matches.extend(truncated);
+/// The detection pipeline, given the already-compiled size-limited set (if any).
+fn collect_with(text: &str, options: &DetectorOptions, limited: Option<&[Regex]>) -> Vec<Match> {
+    let prepared = Prepared::new(text, options);
+    let mut pipeline = Pipeline::new(prepared.scanned(text), options, limited);
+    while pipeline.step() {}
+    prepared.restore(text, pipeline.finish(), options)
+}
 
-    matches
+/// Text as the pipeline sees it: wrapped lines reflowed (with
+/// `DetectorOptions::reflow`) and bidi controls stripped, keeping what is
+/// needed to map matches back onto the original.
+pub(crate) struct Prepared {
+    reflowed: Option<reflow::Reflowed>,
+    stripped: Option<bidi::Stripped>,
 }
 
-// Address detection
+impl Prepared {
+    pub(crate) fn new(text: &str, options: &DetectorOptions) -> Self {
+        let reflowed = options.reflow.then(|| reflow::reflow(text)).flatten();
+        let source = reflowed.as_ref().map_or(text, |r| r.text.as_str());
+        let stripped = bidi::strip(source);
+        Prepared { reflowed, stripped }
+    }
+
+    fn source<'a>(&'a self, text: &'a str) -> &'a str {
+        self.reflowed.as_ref().map_or(text, |r| r.text.as_str())
+    }
+
+    /// The text to run the pipeline over.
+    pub(crate) fn scanned<'a>(&'a self, text: &'a str) -> &'a str {
+        let source = self.source(text);
+        self.stripped.as_ref().map_or(source, |s| s.text.as_str())
+    }
 
-fn find_full_addresses(text: &str, existing_matches: &[Match]) -> Vec<Match> {
-    scan_regex(
-        text,
-        get_full_address_re(),
-        "fullAddress",
-        &[existing_matches],
-    )
+    /// Maps the pipeline's matches back onto `text`.
+    pub(crate) fn restore(
+        &self,
+        text: &str,
+        mut matches: Vec<Match>,
+        options: &DetectorOptions,
+    ) -> Vec<Match> {
+        if let Some(s) = &self.stripped {
+            matches = bidi::restore(self.source(text), s, matches, &options.editable_ranges);
+        }
+        match &self.reflowed {
+            Some(r) => reflow::restore(text, r, matches, &options.editable_ranges),
+            None => matches,
+        }
+    }
 }
 
-fn find_truncated_addresses(text: &str, existing_matches: &[Match]) -> Vec<Match> {
-    scan_regex(text, get_truncated_re(), "truncated", &[existing_matches])
+/// The detection pipeline run one pattern at a time, so async callers can
+/// yield to their executor between pattern families.
+pub(crate) struct Pipeline<'a> {
+    text: &'a str,
+    options: &'a DetectorOptions,
+    limited: Option<&'a [Regex]>,
+    tokens: Vec<Range<usize>>,
+    patterns: Vec<&'static Pattern>,
+    next: usize,
+    matches: Vec<Match>,
+    /// Set by `explain` to record how each whole-text pass changes the matches.
+    trace: Option<explain::PassTrace>,
+    #[cfg(feature = "metrics")]
+    started: std::time::Instant,
 }
 
-fn find_sol_addresses(text: &str, existing_matches: &[Match]) -> Vec<Match> {
-    let mut matches = Vec::new();
+impl<'a> Pipeline<'a> {
+    pub(crate) fn new(
+        text: &'a str,
+        options: &'a DetectorOptions,
+        limited: Option<&'a [Regex]>,
+    ) -> Self {
+        Pipeline {
+            text,
+            options,
+            limited,
+            tokens: tokenize::tokenize(text),
+            patterns: options.prioritize(PATTERNS),
+            next: 0,
+            matches: Vec::new(),
+            trace: None,
+            #[cfg(feature = "metrics")]
+            started: std::time::Instant::now(),
+        }
+    }
 
-    let full = scan_regex(text, get_sol_re(), "sol", &[existing_matches]);
-    matches.extend(full);
+    /// Scans with the next pattern in priority order; returns `false` once
+    /// every pattern has run.
+    pub(crate) fn step(&mut self) -> bool {
+        let Some(pattern) = self.patterns.get(self.next) else {
+            return false;
+        };
+        let re = regex_for(pattern, self.limited);
+        let found = scan_pattern(self.text, &self.tokens, pattern, re, &self.matches);
+        self.matches.extend(found);
+        self.next += 1;
+        true
+    }
 
-    let truncated = scan_regex(
-        text,
-        get_sol_truncated_re(),
-        "sol_truncated",
-        &[existing_matches, &matches],
-    );
-    matches.extend(truncated);
+    /// Runs the whole-text passes and returns the matches sorted by position.
+    pub(crate) fn finish(self) -> Vec<Match> {
+        self.finish_traced().0
+    }
 
-    matches
-}
+    /// Like `finish`, also returning the trace of what each pass changed
+    /// when the pipeline was built by `explain`.
+    pub(crate) fn finish_traced(self) -> (Vec<Match>, Option<explain::PassTrace>) {
+        let Pipeline {
+            text,
+            options,
+            limited,
+            tokens,
+            patterns,
+            mut matches,
+            mut trace,
+            #[cfg(feature = "metrics")]
+            started,
+            ..
+        } = self;
+
+        if options.aggressive {
+            let glued = aggressive::find_glued(text, &patterns, &matches);
+            matches.extend(glued);
+        }
+        explain::record(&mut trace, "aggressive", &matches);
 
-fn find_ens_names(text: &str, existing_matches: &[Match]) -> Vec<Match> {
-    let mut matches = Vec::new();
-    for cap in get_ens_re().find_iter(text) {
-        let val = cap.as_str();
+        rules::scan_loaded(text, options, &mut matches);
+        explain::record(&mut trace, "rules", &matches);
 
-        if !is_valid_ens(val) {
-            continue;
+        if options.collapse_duplicates {
+            collapse_duplicates(text, &tokens, &patterns, limited, &mut matches);
         }
 
-        let start = cap.start();
-        let end = cap.end();
+        #[cfg(feature = "deeplinks")]
+        chains::deeplink::describe_links(&mut matches);
+
+        #[cfg(feature = "evm")]
+        chains::evm::classify_truncated(text, &mut matches, &patterns);
+        explain::record(&mut trace, "evm_truncated", &matches);
+
+        #[cfg(feature = "litecoin")]
+        chains::litecoin::gate_truncated(text, &mut matches, &patterns);
+        explain::record(&mut trace, "litecoin_truncated", &matches);
 
-        if !has_overlap(start, end, existing_matches) {
-            matches.push(Match {
-                value: val.to_string(),
-                index: start,
-                type_: "ens".to_string(),
-            });
+        #[cfg(feature = "xrp")]
+        chains::xrp::gate_truncated(text, &mut matches, &patterns);
+        explain::record(&mut trace, "xrp_truncated", &matches);
+
+        #[cfg(feature = "evm")]
+        if options.strict_hex_case {
+            chains::evm::require_consistent_case(&mut matches);
         }
-    }
-    matches
-}
+        explain::record(&mut trace, "strict_hex_case", &matches);
+
+        #[cfg(feature = "ens")]
+        chains::ens::relabel_namehashes(text, &mut matches, options);
+        explain::record(&mut trace, "ens_namehash", &matches);
+
+        #[cfg(feature = "starknet")]
+        chains::starknet::relabel(text, &mut matches, options);
+        explain::record(&mut trace, "starknet", &matches);
+
+        #[cfg(feature = "evm")]
+        move_address::relabel(text, &mut matches, options);
+        explain::record(&mut trace, "move_address", &matches);
+
+        #[cfg(feature = "evm")]
+        chain_registry::resolve_networks(
+            text,
+            &mut matches,
+            !options.disabled.iter().any(|t| t == "chain_id"),
+        );
+
+        if options.ocr_tolerant {
+            let corrections = ocr::find_corrections(text, &matches);
+            matches.extend(corrections);
+        }
+        explain::record(&mut trace, "ocr", &matches);
+
+        #[cfg(feature = "bech32")]
+        bech32::describe_hrps(&mut matches);
 
-// --- Exported API ---
+        #[cfg(feature = "bitcoin")]
+        chains::bitcoin::describe_networks(&mut matches);
 
-#[wasm_bindgen]
-pub fn find_matches(text: &str) -> JsValue {
-    // Detect transaction hashes first (longer patterns before shorter ones)
-    let mut matches = find_eth_tx_hashes(text);
+        #[cfg(feature = "chia")]
+        chains::chia::describe_networks(&mut matches);
 
-    let eth_tx_truncated = find_eth_tx_truncated(text, &matches);
-    matches.extend(eth_tx_truncated);
+        #[cfg(feature = "stacks")]
+        chains::stacks::describe_networks(&mut matches);
 
-    let sol_tx = find_sol_tx_signatures(text, &matches);
-    matches.extend(sol_tx);
+        #[cfg(feature = "harmony")]
+        if options.normalize {
+            chains::harmony::normalize(&mut matches);
+        }
 
-    // Detect addresses (after tx hashes to avoid partial matches)
-    let full_addresses = find_full_addresses(text, &matches);
-    matches.extend(full_addresses);
+        #[cfg(feature = "filecoin")]
+        chains::filecoin::describe_networks(&mut matches);
 
-    let truncated = find_truncated_addresses(text, &matches);
-    matches.extend(truncated);
+        #[cfg(feature = "avalanche")]
+        chains::avalanche::describe_aliases(&mut matches);
 
-    let sol = find_sol_addresses(text, &matches);
-    matches.extend(sol);
+        #[cfg(feature = "hedera")]
+        chains::hedera::gate_accounts(text, &mut matches);
+        explain::record(&mut trace, "hedera", &matches);
 
-    let ens = find_ens_names(text, &matches);
-    matches.extend(ens);
+        #[cfg(feature = "bitcoincash")]
+        chains::bitcoincash::gate_prefixless(text, &mut matches);
+        explain::record(&mut trace, "bitcoincash", &matches);
 
-    matches.sort_by_key(|m| m.index);
+        #[cfg(feature = "icp")]
+        chains::icp::gate_accounts(text, &mut matches, options);
+        explain::record(&mut trace, "icp", &matches);
 
-    serde_wasm_bindgen::to_value(&matches).unwrap()
+        #[cfg(feature = "near")]
+        chains::near::gate_implicit_accounts(text, &mut matches);
+        explain::record(&mut trace, "near_implicit", &matches);
+
+        #[cfg(feature = "kaspa")]
+        chains::kaspa::gate_addresses(text, &mut matches);
+        explain::record(&mut trace, "kaspa", &matches);
+
+        #[cfg(feature = "eos")]
+        chains::eos::gate_accounts(text, &mut matches);
+        explain::record(&mut trace, "eos", &matches);
+
+        #[cfg(feature = "flow")]
+        chains::flow::gate_addresses(text, &mut matches);
+        explain::record(&mut trace, "flow", &matches);
+
+        #[cfg(feature = "sns")]
+        chains::sns::filter_filenames(text, &mut matches, options);
+        explain::record(&mut trace, "sns_filenames", &matches);
+
+        #[cfg(feature = "ens")]
+        chains::ens::apply_lists(text, &mut matches, options);
+        explain::record(&mut trace, "ens_lists", &matches);
+
+        #[cfg(feature = "ens")]
+        chains::ens::apply_gateways(text, &mut matches, options);
+        explain::record(&mut trace, "ens_gateways", &matches);
+
+        let memo_window = options.memo_window.unwrap_or(memo::DEFAULT_MEMO_WINDOW);
+        memo::pair_memos(text, &mut matches, memo_window);
+        explain::record(&mut trace, "memo", &matches);
+
+        if let Some(window) = options.pii_window {
+            let pii = pii::find_nearby(text, &matches, window, &options.disabled);
+            matches.extend(pii);
+        }
+        explain::record(&mut trace, "pii", &matches);
+
+        for m in &mut matches {
+            let value = m.corrected_value.as_deref().unwrap_or(&m.value);
+            m.compare_key = compare::compare_key(&m.rule_id, value);
+        }
+
+        taxonomy::describe(&mut matches);
+
+        rules::attach_labels(&mut matches);
+
+        matches.sort_by_key(|m| m.index);
+
+        if options.anchors {
+            anchor::attach_anchors(text, &mut matches);
+        }
+
+        for m in &mut matches {
+            m.editable = is_editable(m, &options.editable_ranges);
+        }
+
+        #[cfg(feature = "metrics")]
+        telemetry::record_scan(text.len(), &matches, started.elapsed());
+
+        for m in &mut matches {
+            if let Some(mapped) = options.type_map.get(&m.type_) {
+                m.type_ = mapped.clone();
+            }
+        }
+        explain::record(&mut trace, "type_map", &matches);
+
+        (matches, trace)
+    }
 }
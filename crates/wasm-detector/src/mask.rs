@@ -0,0 +1,280 @@
+use crate::compare::compare_key;
+use crate::{DetectorError, DetectorOptions, Match, PATTERNS, collect_matches_with_options};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Families whose values are bech32 strings: an HRP, the separator `1`, and
+/// data drawn from the bech32 charset.
+const BECH32_FAMILIES: &[&str] = &[
+    "bech32",
+    "cardano",
+    "avalanche",
+    "chia",
+    "harmony",
+    "multiversx",
+];
+
+fn family(rule_id: &str) -> &str {
+    rule_id.split('.').next().unwrap_or_default()
+}
+
+/// Bech32 rules in families that also have Base58 forms. Their truncated
+/// variants share the prefix.
+const BECH32_RULES: &[&str] = &[
+    "bitcoin.segwit_address",
+    "bitcoin.taproot_address",
+    "litecoin.bech32_address",
+    "zcash.shielded_address",
+    "zcash.unified_address",
+];
+
+fn is_bech32(rule_id: &str) -> bool {
+    BECH32_FAMILIES.contains(&family(rule_id))
+        || BECH32_RULES.iter().any(|r| rule_id.starts_with(r))
+}
+
+/// Character every alphanumeric is replaced with, chosen per family so the
+/// masked value stays within the original alphabet (hex stays hex, Base58
+/// stays Base58, bech32 and base32 data stay in their charsets).
+fn placeholder(rule_id: &str) -> char {
+    match family(rule_id) {
+        "evm" | "aptos" | "sui" | "move" | "starknet" | "flow" | "hedera" => '0',
+        "ens" if rule_id == "ens.namehash" => '0',
+        "ens" | "unstoppable" | "sns" => 'x',
+        "pii" if rule_id == "pii.email" => 'x',
+        "pii" => '0',
+        "near" if rule_id == "near.account" => 'x',
+        "near" => '0',
+        "stellar" | "algorand" => 'A',
+        "filecoin" => 'a',
+        "icp" if rule_id == "icp.principal" => 'a',
+        "icp" => '0',
+        "bitcoin" if rule_id == "bitcoin.inscription" => '0',
+        "bitcoincash" | "kaspa" => 'q',
+        _ if is_bech32(rule_id) => 'q',
+        _ => '1',
+    }
+}
+
+/// Name-service suffixes kept as-is.
+const NAME_SUFFIXES: &[&str] = &[".eth", ".near", ".sol"];
+
+/// Length of the structural prefix left untouched: `0x`, a bech32 HRP plus
+/// separator, a cashaddr `bitcoincash:`/`kaspa:` or Nano `nano_` prefix, a
+/// gateway URL's scheme, or a Filecoin network and protocol (`f1`, `t3`).
+fn kept_prefix(m: &Match) -> usize {
+    if m.value.starts_with("0x") {
+        2
+    } else if is_bech32(&m.rule_id) {
+        // A memo may have been folded in after the address; look only at the address.
+        let address = m.metadata.get("address").unwrap_or(&m.value);
+        // Truncated cashaddr forms reach here through the generic bech32 rule.
+        address
+            .find(':')
+            .or_else(|| address.rfind('1'))
+            .map_or(0, |i| i + 1)
+    } else if m.rule_id == "ens.gateway_url" {
+        m.value.find("://").map_or(0, |i| i + 3)
+    } else if m.rule_id == "filecoin.address" {
+        2
+    } else if matches!(family(&m.rule_id), "bitcoincash" | "kaspa") {
+        m.value.find(':').map_or(0, |i| i + 1)
+    } else if family(&m.rule_id) == "nano" {
+        m.value.find('_').map_or(0, |i| i + 1)
+    } else {
+        0
+    }
+}
+
+/// Masks a value without changing its byte length or character classes.
+/// Only ASCII alphanumerics are replaced, one for one, so multi-byte
+/// characters and grapheme clusters inside a match are never split.
+/// Separators (`0x`, `...`, `…`, `.eth`/`.near`/`.sol`, hyphens) are kept so the masked text
+/// still reads as the same kind of value.
+pub fn mask_value(m: &Match) -> String {
+    let fill = placeholder(&m.rule_id);
+    let keep = kept_prefix(m);
+    let body_end = NAME_SUFFIXES
+        .iter()
+        .find_map(|suffix| m.value.strip_suffix(suffix))
+        .map(str::len)
+        .or_else(|| {
+            // Unstoppable names keep their TLD, which varies.
+            (family(&m.rule_id) == "unstoppable")
+                .then(|| m.value.rfind('.'))
+                .flatten()
+        })
+        .or_else(|| {
+            // A gateway URL keeps its `.eth.limo` host.
+            (m.rule_id == "ens.gateway_url")
+                .then(|| m.value.find(".eth."))
+                .flatten()
+        })
+        .unwrap_or(m.value.len());
+
+    m.value
+        .char_indices()
+        .map(|(i, c)| {
+            if i < keep || i >= body_end || !c.is_ascii_alphanumeric() {
+                c
+            } else {
+                fill
+            }
+        })
+        .collect()
+}
+
+/// Replaces every match in `haystack` in place with its same-length mask,
+/// so diffs, column-sensitive formats, and fixed-width parsers stay valid.
+/// With the `audit` feature, each replacement is reported to the audit sink.
+pub fn mask_bytes(haystack: &mut [u8], matches: &[Match]) {
+    for m in matches {
+        let masked = mask_value(m);
+        for start in m.starts() {
+            haystack[start..start + masked.len()].copy_from_slice(masked.as_bytes());
+        }
+        #[cfg(feature = "audit")]
+        crate::audit::record(m, "masked");
+    }
+}
+
+/// Scans `text` and returns it with every match masked.
+pub fn mask_text(text: &str, options: &DetectorOptions) -> Result<String, DetectorError> {
+    let matches = collect_matches_with_options(text, options)?;
+    let mut bytes = text.as_bytes().to_vec();
+    mask_bytes(&mut bytes, &matches);
+    Ok(String::from_utf8(bytes).expect("masks replace ASCII with ASCII"))
+}
+
+// --- Masking Policy ---
+
+/// How one kind of value is rewritten.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[serde(tag = "style", rename_all = "snake_case")]
+pub enum MaskStyle {
+    /// Same-length placeholder mask, as `mask_value` produces.
+    #[default]
+    Placeholder,
+    /// The value is cut from the text entirely.
+    Remove,
+    /// `head` leading and `tail` trailing characters after the structural
+    /// prefix stay readable; the rest is masked.
+    Partial { head: usize, tail: usize },
+}
+
+/// Per-type masking styles, e.g. placeholders for addresses, removal for
+/// keys and a partial reveal for transaction hashes. Types not listed use
+/// `default`.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct MaskPolicy {
+    pub default: MaskStyle,
+    pub types: BTreeMap<String, MaskStyle>,
+}
+
+impl MaskPolicy {
+    pub fn style_for(&self, type_: &str) -> MaskStyle {
+        self.types.get(type_).copied().unwrap_or(self.default)
+    }
+}
+
+/// Like `mask_value`, leaving `head` alphanumerics after the kept prefix and
+/// the last `tail` alphanumerics of the body as written.
+fn mask_partial(m: &Match, head: usize, tail: usize) -> String {
+    let keep = kept_prefix(m);
+    let total = m.value[keep..]
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .count();
+    let mut rank = 0;
+    m.value
+        .char_indices()
+        .zip(mask_value(m).chars())
+        .map(|((i, original), masked)| {
+            if i < keep || !original.is_ascii_alphanumeric() {
+                return masked;
+            }
+            rank += 1;
+            if rank <= head || rank + tail > total {
+                original
+            } else {
+                masked
+            }
+        })
+        .collect()
+}
+
+/// Scans `text` and rewrites each match in the style `policy` gives its
+/// type. Unlike `mask_text`, the result may be shorter than the input when
+/// a style removes values.
+pub fn apply_policy(
+    text: &str,
+    options: &DetectorOptions,
+    policy: &MaskPolicy,
+) -> Result<String, DetectorError> {
+    let matches = collect_matches_with_options(text, options)?;
+    let mut hits: Vec<(usize, &Match)> = matches
+        .iter()
+        .flat_map(|m| m.starts().into_iter().map(move |start| (start, m)))
+        .collect();
+    hits.sort_by_key(|&(start, _)| start);
+
+    let mut out = String::with_capacity(text.len());
+    let mut cursor = 0;
+    for (start, m) in hits {
+        if start < cursor {
+            continue;
+        }
+        out.push_str(&text[cursor..start]);
+        let style = policy.style_for(&m.type_);
+        match style {
+            MaskStyle::Placeholder => out.push_str(&mask_value(m)),
+            MaskStyle::Remove => {}
+            MaskStyle::Partial { head, tail } => out.push_str(&mask_partial(m, head, tail)),
+        }
+        #[cfg(feature = "audit")]
+        crate::audit::record(
+            m,
+            if style == MaskStyle::Remove {
+                "removed"
+            } else {
+                "masked"
+            },
+        );
+        cursor = start + m.value.len();
+    }
+    out.push_str(&text[cursor..]);
+    Ok(out)
+}
+
+/// Whether the value is already some family's mask, e.g. a masked bech32
+/// address that the Base58 pattern picks up on re-scan.
+fn is_mask(m: &Match) -> bool {
+    PATTERNS.iter().any(|p| {
+        let as_family = Match {
+            rule_id: p.rule_id.to_string(),
+            ..m.clone()
+        };
+        mask_value(&as_family) == m.value
+    })
+}
+
+/// Re-scans masked output and returns whatever is still detected: values
+/// that slipped past masking, or were formed by it. Masks themselves
+/// (`0x000...`) and values in `allowlist` (compared by `compare_key`) are
+/// not leaks. An empty result is the all-clear.
+pub fn verify_masked(
+    masked: &str,
+    options: &DetectorOptions,
+    allowlist: &[String],
+) -> Result<Vec<Match>, DetectorError> {
+    Ok(collect_matches_with_options(masked, options)?
+        .into_iter()
+        .filter(|m| {
+            let allowed = allowlist
+                .iter()
+                .any(|a| compare_key(&m.rule_id, a) == m.compare_key);
+            !is_mask(m) && !allowed
+        })
+        .collect())
+}
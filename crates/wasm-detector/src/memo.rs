@@ -0,0 +1,72 @@
+use crate::{Match, grapheme, taxonomy};
+use regex::Regex;
+use std::sync::OnceLock;
+
+/// Characters after an address searched for a memo label when the options
+/// leave `memo_window` unset.
+pub(crate) const DEFAULT_MEMO_WINDOW: usize = 32;
+
+/// Bytes searched past the window for the label and memo themselves: the
+/// longest label with its separators and a 64-character memo.
+const MEMO_SPAN: usize = 96;
+
+static MEMO_RE: OnceLock<Regex> = OnceLock::new();
+
+fn get_memo_re() -> &'static Regex {
+    MEMO_RE.get_or_init(|| {
+        Regex::new(concat!(
+            r"(?i)^\s*(?:memo|destination\s+tag|tag)\b",
+            r"(?:\s*[:#=]\s*([a-z0-9]+)|\s+([a-z]*[0-9][a-z0-9]*))\b",
+        ))
+        .unwrap()
+    })
+}
+
+/// Extends each address or account match followed, after at most `window` characters of
+/// whitespace, by a `memo`/`tag` label and value into one composite match, so masking the
+/// address also masks the memo that identifies the exchange account. The
+/// original address and the memo are kept in `metadata`.
+pub(crate) fn pair_memos(text: &str, matches: &mut [Match], window: usize) {
+    if window == 0 {
+        return;
+    }
+    matches.sort_by_key(|m| m.index);
+
+    for i in 0..matches.len() {
+        // Pairing runs before `taxonomy::describe` fills `kind`.
+        if taxonomy::kind_of(&matches[i].rule_id) != "address" {
+            continue;
+        }
+        let end = matches[i].index + matches[i].value.len();
+        let mut limit =
+            (end + grapheme::after(text, end, window).len() + MEMO_SPAN).min(text.len());
+        while !text.is_char_boundary(limit) {
+            limit += 1;
+        }
+        let Some(caps) = get_memo_re().captures(&text[end..limit]) else {
+            continue;
+        };
+        // Without a separator the value needs a digit, so prose such as
+        // "tag me" is not read as a memo.
+        let memo = caps.get(1).or_else(|| caps.get(2)).unwrap();
+        let gap = text[end..].len() - text[end..].trim_start().len();
+        if grapheme::len(&text[end..end + gap]) > window || memo.len() > 64 {
+            continue;
+        }
+
+        let memo_end = end + memo.end();
+        // A memo running into the search limit may continue past it.
+        if memo_end == limit && text[limit..].starts_with(|c: char| c.is_ascii_alphanumeric()) {
+            continue;
+        }
+        if matches.get(i + 1).is_some_and(|next| next.index < memo_end) {
+            continue;
+        }
+
+        let m = &mut matches[i];
+        m.metadata.insert("address".to_string(), m.value.clone());
+        m.metadata
+            .insert("memo".to_string(), memo.as_str().to_string());
+        m.value = text[m.index..memo_end].to_string();
+    }
+}
@@ -0,0 +1,81 @@
+use crate::bytes::scan_bytes;
+use crate::chunked::overlap;
+use crate::{DetectorOptions, Match, group_and_cap, is_editable, limited_regexes};
+use memmap2::Mmap;
+use std::fs::File;
+use std::io;
+use std::path::Path;
+
+/// Default amount of the file scanned per window.
+pub const DEFAULT_CHUNK_SIZE: usize = 8 * 1024 * 1024;
+
+fn is_word_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_' || b >= 0x80
+}
+
+/// Scans a memory-mapped file window by window, handing each match to
+/// `on_match` in file order. Only one window's matches are held at a time,
+/// so peak memory stays bounded no matter how large the file is. Matches are
+/// not grouped or capped, since later windows are not yet scanned.
+pub fn scan_file_chunked<F>(
+    path: &Path,
+    chunk_size: usize,
+    options: &DetectorOptions,
+    mut on_match: F,
+) -> io::Result<()>
+where
+    F: FnMut(Match),
+{
+    let limited = limited_regexes(options).map_err(io::Error::other)?;
+    let file = File::open(path)?;
+    if file.metadata()?.len() == 0 {
+        return Ok(());
+    }
+
+    // SAFETY: the map is read-only and dropped before returning; as with any
+    // mmap, concurrent truncation of the file by another process is not supported.
+    let map = unsafe { Mmap::map(&file)? };
+    let data: &[u8] = &map;
+    // Each window runs `overlap` bytes past its chunk, the longest value any
+    // pattern can match, so values straddling a chunk edge are found whole.
+    let overlap = overlap();
+    let chunk_size = chunk_size.max(overlap);
+
+    let mut start = 0;
+    while start < data.len() {
+        let chunk_end = (start + chunk_size).min(data.len());
+        let window_end = (chunk_end + overlap).min(data.len());
+        let window = &data[start..window_end];
+
+        for mut m in scan_bytes(window, options, limited.as_deref()) {
+            // Matches starting in the overlap belong to the next window.
+            if start + m.index >= chunk_end {
+                continue;
+            }
+            // A hit touching a cut edge is the tail or head of a longer token.
+            let cut_before = m.index == 0 && start > 0 && is_word_byte(data[start - 1]);
+            let end = m.index + m.value.len();
+            let cut_after =
+                end == window.len() && window_end < data.len() && is_word_byte(data[window_end]);
+            if cut_before || cut_after {
+                continue;
+            }
+
+            m.shift(start);
+            m.editable = is_editable(&m, &options.editable_ranges);
+            on_match(m);
+        }
+
+        start = chunk_end;
+    }
+
+    Ok(())
+}
+
+/// Scans a whole file through a memory map and returns every match, with
+/// `group_positions` and `max_matches` applied.
+pub fn scan_file(path: &Path, options: &DetectorOptions) -> io::Result<Vec<Match>> {
+    let mut matches = Vec::new();
+    scan_file_chunked(path, DEFAULT_CHUNK_SIZE, options, |m| matches.push(m))?;
+    Ok(group_and_cap(matches, options).matches)
+}
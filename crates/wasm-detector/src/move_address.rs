@@ -0,0 +1,91 @@
+//! Aptos and Sui account addresses share the `0x` + 64 hex shape of EVM
+//! transaction hashes. This pass relabels a `evm.tx_hash` match as a Move
+//! chain address only when the text points at a Move chain:
+//!
+//! - a chain name (`aptos`, `sui`) shortly before it picks the chain, and
+//!   `DetectorOptions::hex64_chain` supplies one when the text names none;
+//! - a `::` module path after it (`0x1::coin::CoinStore`) is Move syntax
+//!   and always an address.
+//!
+//! Given such context, address words (`account`, `address`, `object`, ...)
+//! or a long run of leading zeros (padded system addresses such as
+//! `0x000…001`) mark it as an address rather than a transaction hash, while
+//! `tx`/`hash` words keep it a hash. Address words alone are not enough:
+//! they are just as common next to EVM hashes.
+//!
+//! Module paths with no chain named become `move_address` with both chains
+//! listed in `possible_chains`.
+
+use crate::{DetectorOptions, Match, grapheme};
+
+/// Grapheme clusters before a value searched for context words.
+const CONTEXT_WINDOW: usize = 48;
+
+/// Leading zero digits (after `0x`) that no random hash plausibly has.
+const PADDED_ZEROS: usize = 8;
+
+const CHAINS: &[&str] = &["aptos", "sui"];
+const ADDRESS_WORDS: &[&str] = &[
+    "account", "address", "addr", "object", "package", "module", "owner", "wallet",
+];
+const TX_WORDS: &[&str] = &["tx", "txn", "transaction", "hash", "digest"];
+
+/// The context words before `index`, lowercased, nearest last.
+pub(crate) fn words_before(text: &str, index: usize) -> Vec<String> {
+    grapheme::before(text, index, CONTEXT_WINDOW)
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_ascii_lowercase)
+        .collect()
+}
+
+/// Position of the nearest word from `set`, counted from the value backwards.
+pub(crate) fn nearest(words: &[String], set: &[&str]) -> Option<usize> {
+    words.iter().rev().position(|w| set.contains(&w.as_str()))
+}
+
+pub(crate) fn relabel(text: &str, matches: &mut [Match], options: &DetectorOptions) {
+    let hint = options
+        .hex64_chain
+        .as_deref()
+        .filter(|c| CHAINS.contains(c));
+    for m in matches.iter_mut().filter(|m| m.rule_id == "evm.tx_hash") {
+        let words = words_before(text, m.index);
+        let named = CHAINS
+            .iter()
+            .filter_map(|c| nearest(&words, &[c]).map(|d| (d, *c)))
+            .min()
+            .map(|(_, c)| c);
+        let module_path = text[m.index + m.value.len()..].starts_with("::");
+        if named.is_none() && hint.is_none() && !module_path {
+            continue;
+        }
+
+        let padded = m.value[2..].starts_with(&"0".repeat(PADDED_ZEROS));
+        let address_word = nearest(&words, ADDRESS_WORDS);
+        let tx_word = nearest(&words, TX_WORDS);
+        let is_address = match (address_word, tx_word) {
+            (Some(a), Some(t)) => a < t,
+            (Some(_), None) => true,
+            (None, Some(_)) => padded,
+            (None, None) => padded || hint.is_some(),
+        };
+        if !is_address && !module_path {
+            continue;
+        }
+
+        let (type_, rule_id) = match named.or(hint) {
+            Some("aptos") => ("aptos_address", "aptos.address"),
+            Some(_) => ("sui_address", "sui.address"),
+            None => ("move_address", "move.address"),
+        };
+        if options.disabled.iter().any(|t| t == type_) {
+            continue;
+        }
+        if type_ == "move_address" {
+            m.possible_chains = CHAINS.iter().map(|c| c.to_string()).collect();
+        }
+        m.type_ = type_.to_string();
+        m.rule_id = rule_id.to_string();
+    }
+}
@@ -0,0 +1,127 @@
+//! Noise-tolerant matching for OCR output. A candidate that fails detection
+//! is accepted when exactly one common OCR confusion (O/0, l/1, rn/m) turns
+//! it into a checksum-valid address; both the raw and corrected text are
+//! reported.
+
+use crate::{Match, has_overlap};
+use regex::Regex;
+use std::collections::BTreeMap;
+#[cfg(any(feature = "evm", feature = "bech32"))]
+use std::sync::OnceLock;
+
+/// Character confusions typical of OCR engines, as (seen, meant) pairs.
+const CONFUSIONS: &[(&str, &str)] = &[
+    ("O", "0"),
+    ("o", "0"),
+    ("0", "o"),
+    ("l", "1"),
+    ("I", "1"),
+    ("I", "l"),
+    ("1", "l"),
+    ("i", "l"),
+    ("rn", "m"),
+    ("m", "rn"),
+];
+
+/// A family that can be OCR-corrected: a lenient candidate regex and the
+/// checksum test a corrected value must pass.
+struct OcrRule {
+    type_: &'static str,
+    rule_id: &'static str,
+    regex: fn() -> &'static Regex,
+    is_valid: fn(&str) -> bool,
+}
+
+#[cfg(feature = "evm")]
+fn get_evm_ocr_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"\b[0O][xX][0-9a-fA-FOoIl]{40}\b").unwrap())
+}
+
+#[cfg(feature = "bech32")]
+fn get_bech32_ocr_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"\b[a-z0-9]{1,20}1[a-z0-9OI]{6,112}\b").unwrap())
+}
+
+#[cfg(feature = "bech32")]
+fn is_valid_bech32(value: &str) -> bool {
+    crate::bech32::resolve_chain(value).is_some()
+}
+
+const OCR_RULES: &[OcrRule] = &[
+    #[cfg(feature = "evm")]
+    OcrRule {
+        type_: "fullAddress",
+        rule_id: "evm.address.ocr",
+        regex: get_evm_ocr_re,
+        is_valid: crate::chains::evm::is_eip55_checksummed,
+    },
+    #[cfg(feature = "bech32")]
+    OcrRule {
+        type_: "bech32_address",
+        rule_id: "bech32.address.ocr",
+        regex: get_bech32_ocr_re,
+        is_valid: is_valid_bech32,
+    },
+];
+
+/// Every string reachable from `raw` by applying one confusion at one position.
+fn single_edits(raw: &str) -> Vec<String> {
+    let mut variants = Vec::new();
+    for (seen, meant) in CONFUSIONS {
+        for (i, _) in raw.match_indices(seen) {
+            variants.push(format!("{}{meant}{}", &raw[..i], &raw[i + seen.len()..]));
+        }
+    }
+    variants
+}
+
+/// Returns the unique one-edit correction of `raw` that `is_valid` accepts.
+/// Ambiguous inputs (several valid corrections) are not corrected.
+fn correct(raw: &str, is_valid: fn(&str) -> bool) -> Option<String> {
+    if is_valid(raw) {
+        return None;
+    }
+    let mut valid = single_edits(raw).into_iter().filter(|v| is_valid(v));
+    let first = valid.next()?;
+    if valid.any(|v| v != first) {
+        return None;
+    }
+    Some(first)
+}
+
+/// Finds OCR-damaged addresses in spans not already claimed by `matches`.
+pub(crate) fn find_corrections(text: &str, matches: &[Match]) -> Vec<Match> {
+    let mut corrections: Vec<Match> = Vec::new();
+    for rule in OCR_RULES {
+        for cap in (rule.regex)().find_iter(text) {
+            let (start, end) = (cap.start(), cap.end());
+            if has_overlap(start, end, matches) || has_overlap(start, end, &corrections) {
+                continue;
+            }
+            let Some(corrected) = correct(cap.as_str(), rule.is_valid) else {
+                continue;
+            };
+            corrections.push(Match {
+                value: cap.as_str().to_string(),
+                index: start,
+                type_: rule.type_.to_string(),
+                rule_id: rule.rule_id.to_string(),
+                compare_key: String::new(),
+                metadata: BTreeMap::new(),
+                possible_chains: Vec::new(),
+                corrected: true,
+                corrected_value: Some(corrected),
+                editable: false,
+                positions: Vec::new(),
+                network: None,
+                normalized: None,
+                chain: None,
+                kind: String::new(),
+                variant: None,
+            });
+        }
+    }
+    corrections
+}
@@ -0,0 +1,235 @@
+use crate::{PATTERNS, Pattern};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Per-call configuration supplied by the host.
+#[derive(Serialize, Deserialize, Default, Clone)]
+#[serde(default)]
+pub struct DetectorOptions {
+    /// Types scanned ahead of the global priority table, in the listed order.
+    pub promote: Vec<String>,
+    /// Types scanned after everything else, in the listed order.
+    pub demote: Vec<String>,
+    /// Types that are not scanned at all.
+    pub disabled: Vec<String>,
+    /// Per-family switch for truncated detection, keyed by the full pattern's
+    /// rule id (e.g. `{ "evm.address": false }` keeps `0x…` addresses out while
+    /// full addresses are still reported). Families not listed stay enabled.
+    pub truncated: BTreeMap<String, bool>,
+    /// Also look for values glued to neighbouring text, with word-boundary
+    /// anchors dropped. Such matches carry a `confidence` score in metadata.
+    pub aggressive: bool,
+    /// Annotate matches whose exact string also fits another chain's pattern
+    /// with `possible_chains`, rather than silently keeping only the winner.
+    pub collapse_duplicates: bool,
+    /// Lenient mode for OCR'd text: accept values one OCR confusion (O/0,
+    /// l/1, rn/m) away from a checksum-valid address, flagged `corrected`.
+    pub ocr_tolerant: bool,
+    /// Attach `anchor_before`/`anchor_after` context hashes to each match's
+    /// metadata so hosts can re-find it after minor text changes.
+    pub anchors: bool,
+    /// Characters after an address searched for a `memo`/`tag` label whose
+    /// value is folded into the address match. Defaults to 32; 0 disables it.
+    pub memo_window: Option<usize>,
+    /// Characters around a wallet match searched for email addresses and
+    /// phone numbers, reported as `email`/`phone` so scrubbing removes the
+    /// contact details sent along with an address. Off when unset.
+    pub pii_window: Option<usize>,
+    /// Chain assumed for `0x` + 64-hex account addresses when the text does
+    /// not name one: `"aptos"` or `"sui"`. Without it, such values stay
+    /// `eth_tx_hash` unless their context or shape marks them as addresses.
+    pub hex64_chain: Option<String>,
+    /// Report Starknet addresses (`0x` + up to 64 hex digits below the Stark
+    /// prime) even when no `starknet` word precedes them. Off by default,
+    /// since full-width ones are otherwise EVM transaction hashes.
+    pub starknet: bool,
+    /// Fill each match's `normalized` field with its canonical equivalent
+    /// where one exists, e.g. the `0x` form of a Harmony `one1…` address.
+    pub normalize: bool,
+    /// Drop complete `0x` hex values in mixed case unless they are EIP-55
+    /// checksummed addresses; all-lowercase and all-uppercase values are
+    /// kept. Cuts noise from random hex-like tokens in minified JS.
+    pub strict_hex_case: bool,
+    /// ENS names never reported, e.g. `this.eth` written as a joke.
+    /// Compared case-insensitively.
+    pub ens_allowlist: Vec<String>,
+    /// Names always reported as `ens`, e.g. a known phishing name, wherever
+    /// they appear as a whole word; such matches carry `denylisted`.
+    pub ens_denylist: Vec<String>,
+    /// What to do with names served through a gateway (`vitalik.eth.limo`,
+    /// `.eth.link`): `"name"` (the default) reports just the name,
+    /// `"ignore"` leaves gateway links alone, and `"url"` reports the whole
+    /// URL as `ens_gateway_url`.
+    pub ens_gateways: Option<String>,
+    /// Report `.sol` names only with an SNS word (`sns`, `bonfida`,
+    /// `solana`) nearby, for text full of Solidity file names. Otherwise
+    /// only names that look like files (`Token.sol`, `contracts/lib.sol`)
+    /// need the word.
+    pub sns_require_context: bool,
+    /// Renames match types onto the host's own taxonomy, e.g.
+    /// `{ "eth_tx_hash": "TRANSACTION", "ens": "HANDLE" }`. Applied last, so
+    /// every other option still refers to the built-in type names.
+    pub type_map: BTreeMap<String, String>,
+    /// Byte ranges `[start, end)` of the text that the user can edit (form
+    /// fields, contenteditable regions). Matches overlapping one are tagged
+    /// `editable` so the host can hold off masking text still being typed.
+    pub editable_ranges: Vec<[usize; 2]>,
+    /// Report each distinct (value, type) once, with the byte offset of
+    /// every occurrence in `positions`, instead of one match per occurrence.
+    /// Shrinks results for pages that repeat the same address many times.
+    pub group_positions: bool,
+    /// Scan text as if wrapped lines were rejoined: `> ` quote prefixes are
+    /// dropped and `-` line breaks inside a token joined, so a value split
+    /// across lines of a quoted email or a PDF is still found. Its span in
+    /// the original then covers the break, and `metadata.reflowed` holds the
+    /// joined value.
+    pub reflow: bool,
+    /// In `collect_matches_html`, also scan the bodies of `<!-- -->`
+    /// comments, where some sites leave full addresses behind.
+    pub html_comments: bool,
+    /// In `collect_matches_html`, also scan `<meta content>` values, e.g.
+    /// an address in an `og:description`.
+    pub html_meta: bool,
+    /// Cap on the number of matches returned. Past it, complete values are
+    /// kept before low-confidence, truncated and context matches, and
+    /// `collect_matches_capped` reports the dropped counts per type.
+    pub max_matches: Option<usize>,
+    /// Cap in bytes on each compiled regex program (`RegexBuilder::size_limit`).
+    pub regex_size_limit: Option<usize>,
+    /// Cap in bytes on each regex's lazy DFA cache (`RegexBuilder::dfa_size_limit`).
+    pub dfa_size_limit: Option<usize>,
+}
+
+impl DetectorOptions {
+    fn is_enabled(&self, pattern: &Pattern) -> bool {
+        if !crate::chains::kind_compiled(pattern)
+            || self.disabled.iter().any(|t| t == pattern.type_)
+        {
+            return false;
+        }
+        match pattern.rule_id.strip_suffix(".truncated") {
+            Some(family) => self.truncated.get(family).copied().unwrap_or(true),
+            None => true,
+        }
+    }
+
+    /// Reorders the pattern table: promoted types first, untouched types in
+    /// their global order, demoted types last. Earlier patterns win overlaps.
+    /// Disabled types and switched-off truncated families are dropped. A
+    /// loaded rules bundle's promote/demote lists rank after these options'.
+    pub fn prioritize<'a>(&self, patterns: &'a [Pattern]) -> Vec<&'a Pattern> {
+        let (bundle_promote, bundle_demote) = crate::rules::priorities();
+        let promote: Vec<&String> = self.promote.iter().chain(&bundle_promote).collect();
+        let demote: Vec<&String> = self.demote.iter().chain(&bundle_demote).collect();
+        let rank = |pattern: &Pattern| {
+            let type_ = pattern.type_;
+            if let Some(i) = promote.iter().position(|t| *t == type_) {
+                (0, i)
+            } else if let Some(i) = demote.iter().position(|t| *t == type_) {
+                (2, i)
+            } else {
+                (1, 0)
+            }
+        };
+
+        let mut ordered: Vec<&Pattern> = patterns.iter().filter(|p| self.is_enabled(p)).collect();
+        ordered.sort_by_key(|p| rank(p));
+        ordered
+    }
+}
+
+// --- Defaults & Schema ---
+
+/// The options in effect when none are given, with implicit defaults spelled out.
+pub fn default_options() -> DetectorOptions {
+    DetectorOptions {
+        memo_window: Some(crate::memo::DEFAULT_MEMO_WINDOW),
+        ..DetectorOptions::default()
+    }
+}
+
+/// JSON Schema (draft 2020-12) for `DetectorOptions`, listing only the match
+/// types and truncated families compiled into this build.
+pub fn options_schema() -> Value {
+    let compiled = PATTERNS.iter().filter(|p| crate::chains::kind_compiled(p));
+    let mut types: BTreeSet<&str> = compiled.clone().map(|p| p.type_).collect();
+    if cfg!(feature = "evm") {
+        types.extend([
+            "chain_id",
+            "aptos_address",
+            "sui_address",
+            "move_address",
+            "ambiguous_truncated",
+        ]);
+    }
+    if cfg!(feature = "ens") {
+        types.extend(["ens_namehash", "ens_gateway_url"]);
+    }
+    types.extend(["email", "phone"]);
+    let families: Vec<&str> = compiled
+        .filter_map(|p| p.rule_id.strip_suffix(".truncated"))
+        .collect();
+    let type_list = json!({ "type": "array", "items": { "enum": types }, "uniqueItems": true });
+    let limit = json!({ "type": ["integer", "null"], "minimum": 1 });
+    let name_list = json!({ "type": "array", "items": { "type": "string", "minLength": 1 } });
+
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "DetectorOptions",
+        "type": "object",
+        "additionalProperties": false,
+        "properties": {
+            "promote": type_list,
+            "demote": type_list,
+            "disabled": type_list,
+            "truncated": {
+                "type": "object",
+                "propertyNames": { "enum": families },
+                "additionalProperties": { "type": "boolean" }
+            },
+            "aggressive": { "type": "boolean", "default": false },
+            "collapse_duplicates": { "type": "boolean", "default": false },
+            "ocr_tolerant": { "type": "boolean", "default": false },
+            "anchors": { "type": "boolean", "default": false },
+            "memo_window": {
+                "type": ["integer", "null"],
+                "minimum": 0,
+                "default": crate::memo::DEFAULT_MEMO_WINDOW
+            },
+            "pii_window": { "type": ["integer", "null"], "minimum": 0 },
+            "hex64_chain": { "type": ["string", "null"], "enum": ["aptos", "sui", null] },
+            "starknet": { "type": "boolean", "default": false },
+            "normalize": { "type": "boolean", "default": false },
+            "strict_hex_case": { "type": "boolean", "default": false },
+            "ens_allowlist": name_list,
+            "ens_denylist": name_list,
+            "ens_gateways": {
+                "type": ["string", "null"],
+                "enum": ["name", "ignore", "url", null]
+            },
+            "sns_require_context": { "type": "boolean", "default": false },
+            "type_map": {
+                "type": "object",
+                "propertyNames": { "enum": types },
+                "additionalProperties": { "type": "string", "minLength": 1 }
+            },
+            "editable_ranges": {
+                "type": "array",
+                "items": {
+                    "type": "array",
+                    "items": { "type": "integer", "minimum": 0 },
+                    "minItems": 2,
+                    "maxItems": 2
+                }
+            },
+            "group_positions": { "type": "boolean", "default": false },
+            "reflow": { "type": "boolean", "default": false },
+            "html_comments": { "type": "boolean", "default": false },
+            "html_meta": { "type": "boolean", "default": false },
+            "max_matches": { "type": ["integer", "null"], "minimum": 0 },
+            "regex_size_limit": limit,
+            "dfa_size_limit": limit
+        }
+    })
+}
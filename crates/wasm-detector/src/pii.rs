@@ -0,0 +1,105 @@
+//! Companion PII detection for scrubbing: email addresses and phone numbers
+//! written near wallet data ("send to 0x…, contact me at x@y.com") are
+//! reported alongside it, so one pass scrubs the whole message. Opt-in via
+//! `DetectorOptions::pii_window`; PII far from any wallet match is ignored.
+
+use crate::{Match, grapheme, has_overlap};
+use regex::Regex;
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+/// Digits a phone number needs at minimum (and may have at most, per E.164).
+const PHONE_DIGITS: std::ops::RangeInclusive<usize> = 7..=15;
+
+static EMAIL_RE: OnceLock<Regex> = OnceLock::new();
+static PHONE_RE: OnceLock<Regex> = OnceLock::new();
+
+fn get_email_re() -> &'static Regex {
+    EMAIL_RE.get_or_init(|| {
+        Regex::new(r"\b[A-Za-z0-9._%+-]+@[A-Za-z0-9-]+(?:\.[A-Za-z0-9-]+)*\.[A-Za-z]{2,}\b")
+            .unwrap()
+    })
+}
+
+fn get_phone_re() -> &'static Regex {
+    PHONE_RE.get_or_init(|| {
+        Regex::new(
+            r"(?:\+[0-9]{1,3}[ .-]?)?(?:\([0-9]{1,4}\)[ .-]?)?[0-9]{2,4}(?:[ .-][0-9]{2,4}){1,4}\b",
+        )
+        .unwrap()
+    })
+}
+
+/// Grapheme clusters between the span and the nearest wallet match.
+fn distance(text: &str, start: usize, end: usize, anchors: &[(usize, usize)]) -> Option<usize> {
+    anchors
+        .iter()
+        .map(|&(a_start, a_end)| {
+            if a_end <= start {
+                grapheme::len(&text[a_end..start])
+            } else {
+                grapheme::len(&text[end.min(a_start)..a_start])
+            }
+        })
+        .min()
+}
+
+/// Reports emails and phone numbers within `window` characters of any
+/// existing match, skipping types listed in `disabled`.
+pub(crate) fn find_nearby(
+    text: &str,
+    matches: &[Match],
+    window: usize,
+    disabled: &[String],
+) -> Vec<Match> {
+    let anchors: Vec<(usize, usize)> = matches
+        .iter()
+        .map(|m| (m.index, m.index + m.value.len()))
+        .collect();
+    if anchors.is_empty() {
+        return Vec::new();
+    }
+
+    let kinds = [
+        ("email", "pii.email", get_email_re()),
+        ("phone", "pii.phone", get_phone_re()),
+    ];
+    let mut found: Vec<Match> = Vec::new();
+    for (type_, rule_id, re) in kinds {
+        if disabled.iter().any(|t| t == type_) {
+            continue;
+        }
+        for cap in re.find_iter(text) {
+            let (start, end) = (cap.start(), cap.end());
+            let digits = cap.as_str().bytes().filter(u8::is_ascii_digit).count();
+            if type_ == "phone" && !PHONE_DIGITS.contains(&digits) {
+                continue;
+            }
+            if has_overlap(start, end, matches) || has_overlap(start, end, &found) {
+                continue;
+            }
+            if distance(text, start, end, &anchors).is_none_or(|d| d > window) {
+                continue;
+            }
+            found.push(Match {
+                value: cap.as_str().to_string(),
+                index: start,
+                type_: type_.to_string(),
+                rule_id: rule_id.to_string(),
+                compare_key: String::new(),
+                metadata: BTreeMap::new(),
+                possible_chains: Vec::new(),
+                corrected: false,
+                corrected_value: None,
+                editable: false,
+                positions: Vec::new(),
+                network: None,
+                normalized: None,
+                chain: None,
+                kind: String::new(),
+                variant: None,
+            });
+        }
+    }
+    found
+}
@@ -0,0 +1,44 @@
+use crate::DetectorOptions;
+use std::collections::BTreeMap;
+use std::sync::{OnceLock, RwLock};
+
+/// Site presets bundled with the crate, keyed by host pattern.
+const BUILTIN_PROFILES: &str = include_str!("profiles.json");
+
+static REGISTRY: OnceLock<RwLock<BTreeMap<String, DetectorOptions>>> = OnceLock::new();
+
+fn registry() -> &'static RwLock<BTreeMap<String, DetectorOptions>> {
+    REGISTRY.get_or_init(|| RwLock::new(serde_json::from_str(BUILTIN_PROFILES).unwrap()))
+}
+
+/// Matches `example.com` against itself and its subdomains; a trailing `.*`
+/// (`etherscan.*`) additionally accepts any top-level domain.
+fn host_matches(pattern: &str, host: &str) -> bool {
+    let host = host.trim_end_matches('.').to_ascii_lowercase();
+
+    if let Some(base) = pattern.strip_suffix(".*") {
+        return host.match_indices(base).any(|(i, _)| {
+            (i == 0 || host[..i].ends_with('.')) && host[i + base.len()..].starts_with('.')
+        });
+    }
+
+    host == pattern || host.ends_with(&format!(".{pattern}"))
+}
+
+/// Returns the options of the most specific profile matching `host`, if any.
+pub fn profile_for_host(host: &str) -> Option<DetectorOptions> {
+    let registry = registry().read().unwrap();
+    registry
+        .iter()
+        .filter(|(pattern, _)| host_matches(pattern, host))
+        .max_by_key(|(pattern, _)| pattern.len())
+        .map(|(_, options)| options.clone())
+}
+
+/// Adds or replaces a profile at runtime, overriding any bundled preset for the same pattern.
+pub fn register_profile(pattern: &str, options: DetectorOptions) {
+    registry()
+        .write()
+        .unwrap()
+        .insert(pattern.to_ascii_lowercase(), options);
+}
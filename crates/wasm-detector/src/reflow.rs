@@ -0,0 +1,110 @@
+//! Reflow for text that was wrapped for display: PDFs hyphenate long tokens
+//! across lines (`0x5aAeb6-\nb605…`) and email replies prefix every quoted
+//! line with `> `. With `DetectorOptions::reflow`, scans run on the text
+//! with quote prefixes removed and hyphenated line breaks inside a token
+//! joined, and matches are mapped back onto the original, where their spans
+//! include the break. A match that crossed one carries the joined value as
+//! `metadata.reflowed`.
+
+use crate::{Match, is_editable};
+
+/// Text with quote prefixes and hyphenated breaks removed, and where each
+/// byte came from.
+pub(crate) struct Reflowed {
+    pub(crate) text: String,
+    /// Original byte offset of each reflowed byte, plus one for the end.
+    offsets: Vec<usize>,
+}
+
+/// Length of the quote prefix (`> `, `>> `, `> > `) at the start of `line`,
+/// leading whitespace included; 0 when the line is not quoted.
+fn quote_prefix(line: &str) -> usize {
+    let indent = line.len() - line.trim_start_matches([' ', '\t']).len();
+    let mut end = indent;
+    while line[end..].starts_with('>') {
+        end += 1;
+        end += line[end..].len() - line[end..].trim_start_matches([' ', '\t']).len();
+    }
+    if end == indent { 0 } else { end }
+}
+
+/// Length of a hyphenated break at the start of `rest` (`-`, a line break,
+/// and the next line's quote prefix and indentation) when it splits a token.
+fn hyphen_break(before: Option<char>, rest: &str) -> usize {
+    let Some(after_hyphen) = rest.strip_prefix('-') else {
+        return 0;
+    };
+    let Some(next_line) = after_hyphen
+        .strip_prefix("\r\n")
+        .or_else(|| after_hyphen.strip_prefix('\n'))
+    else {
+        return 0;
+    };
+    let indent = next_line.len() - next_line.trim_start_matches([' ', '\t']).len();
+    let skip = quote_prefix(next_line).max(indent);
+    let joins_token = before.is_some_and(|c| c.is_alphanumeric())
+        && next_line[skip..].starts_with(|c: char| c.is_alphanumeric());
+    if joins_token {
+        rest.len() - next_line.len() + skip
+    } else {
+        0
+    }
+}
+
+/// Removes quote prefixes and joins hyphenated breaks, or returns `None`
+/// when the text has neither.
+pub(crate) fn reflow(text: &str) -> Option<Reflowed> {
+    let mut out = String::with_capacity(text.len());
+    let mut offsets = Vec::with_capacity(text.len() + 1);
+    let mut i = 0;
+    let mut line_start = true;
+    while i < text.len() {
+        let rest = &text[i..];
+        let skip = if line_start {
+            quote_prefix(rest)
+        } else {
+            hyphen_break(out.chars().next_back(), rest)
+        };
+        line_start = false;
+        if skip > 0 {
+            i += skip;
+            continue;
+        }
+        let c = rest.chars().next().unwrap();
+        out.push(c);
+        offsets.extend(i..i + c.len_utf8());
+        line_start = c == '\n';
+        i += c.len_utf8();
+    }
+    if out.len() == text.len() {
+        return None;
+    }
+    offsets.push(text.len());
+    Some(Reflowed { text: out, offsets })
+}
+
+/// Moves matches found in the reflowed text back onto `original`.
+/// Editable ranges refer to the original text, so they are re-applied.
+pub(crate) fn restore(
+    original: &str,
+    reflowed: &Reflowed,
+    matches: Vec<Match>,
+    editable_ranges: &[[usize; 2]],
+) -> Vec<Match> {
+    matches
+        .into_iter()
+        .map(|mut m| {
+            let start = reflowed.offsets[m.index];
+            let last = m.index + m.value.len() - 1;
+            let end = reflowed.offsets[last] + 1;
+            let spanned = &original[start..end];
+            if spanned != m.value {
+                m.metadata.insert("reflowed".to_string(), m.value.clone());
+                m.value = spanned.to_string();
+            }
+            m.index = start;
+            m.editable = is_editable(&m, editable_ranges);
+            m
+        })
+        .collect()
+}
@@ -0,0 +1,158 @@
+//! Redaction reports: what was found and what policy applies to it, without
+//! any of the values themselves, for attaching to support tickets or
+//! compliance records.
+
+use crate::Match;
+use crate::detector::ScanResult;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+/// How much a value of this kind reveals about its owner.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    /// Context only, such as a chain id.
+    Low,
+    /// A shortened value that narrows down but does not pin an account.
+    Medium,
+    /// A complete address, name, transaction or link.
+    High,
+}
+
+/// What the policy does with a match of a given severity.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Action {
+    Mask,
+    Flag,
+}
+
+impl Action {
+    pub fn name(self) -> &'static str {
+        match self {
+            Action::Mask => "mask",
+            Action::Flag => "flag",
+        }
+    }
+}
+
+impl Severity {
+    pub(crate) fn for_rule(rule_id: &str) -> Self {
+        if rule_id.starts_with("evm.chain_id") {
+            Severity::Low
+        } else if rule_id.ends_with(".truncated") {
+            Severity::Medium
+        } else {
+            Severity::High
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Severity::Low => "low",
+            Severity::Medium => "medium",
+            Severity::High => "high",
+        }
+    }
+
+    fn action(self) -> Action {
+        match self {
+            Severity::Low => Action::Flag,
+            Severity::Medium | Severity::High => Action::Mask,
+        }
+    }
+}
+
+/// One line of a report: every match sharing a rule.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct ReportEntry {
+    pub rule_id: String,
+    pub type_: String,
+    pub severity: Severity,
+    pub action: Action,
+    pub count: usize,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub detector_version: &'static str,
+    pub total: usize,
+    /// Match counts per type, across rules.
+    pub counts: BTreeMap<String, usize>,
+    /// Highest severity first, then by rule id.
+    pub entries: Vec<ReportEntry>,
+}
+
+impl Report {
+    fn from_rules<'a>(rules: impl Iterator<Item = (&'a str, &'a str)>) -> Self {
+        let mut by_rule: BTreeMap<(&str, &str), usize> = BTreeMap::new();
+        for rule in rules {
+            *by_rule.entry(rule).or_default() += 1;
+        }
+
+        let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+        let mut entries: Vec<ReportEntry> = by_rule
+            .into_iter()
+            .map(|((rule_id, type_), count)| {
+                *counts.entry(type_.to_string()).or_default() += count;
+                let severity = Severity::for_rule(rule_id);
+                ReportEntry {
+                    rule_id: rule_id.to_string(),
+                    type_: type_.to_string(),
+                    severity,
+                    action: severity.action(),
+                    count,
+                }
+            })
+            .collect();
+        entries.sort_by(|a, b| b.severity.cmp(&a.severity).then(a.rule_id.cmp(&b.rule_id)));
+
+        Report {
+            detector_version: env!("CARGO_PKG_VERSION"),
+            total: counts.values().sum(),
+            counts,
+            entries,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+
+    /// Plain-text rendering, one rule per line.
+    pub fn to_text(&self) -> String {
+        let mut out = format!(
+            "wallet-mask redaction report (detector {})\n{} match(es)\n",
+            self.detector_version, self.total
+        );
+        for e in &self.entries {
+            let _ = writeln!(
+                out,
+                "  {:<36} {:<22} {:<7} {:<5} {}",
+                e.rule_id,
+                e.type_,
+                e.severity.name(),
+                e.action.name(),
+                e.count
+            );
+        }
+        out
+    }
+}
+
+/// Builds the report for one scan's matches.
+pub fn report(matches: &[Match]) -> Report {
+    Report::from_rules(
+        matches
+            .iter()
+            .map(|m| (m.rule_id.as_str(), m.type_.as_str())),
+    )
+}
+
+impl ScanResult<'_> {
+    /// Redaction report for this scan.
+    pub fn report(&self) -> Report {
+        Report::from_rules(self.iter().map(|m| (m.rule_id, m.type_)))
+    }
+}
@@ -0,0 +1,274 @@
+//! Rules bundles loaded at runtime, so pattern improvements reach hosts
+//! between wasm releases. A bundle adds detection rules, value labels, and
+//! priority hints on top of the built-in table:
+//!
+//! ```json
+//! {
+//!   "version": 7,
+//!   "rules": [{ "rule_id": "acme.deposit_ref", "type": "deposit_ref",
+//!               "chain": "acme", "pattern": "\\bACME-[0-9]{8}\\b" }],
+//!   "labels": { "0x28c6c06298d514db089934071355e5743bf21d60": "Binance 14" },
+//!   "promote": ["sol_address"],
+//!   "demote": []
+//! }
+//! ```
+//!
+//! A bundle must carry an ed25519 signature over its exact JSON bytes from a
+//! key the host pinned with `trust_rules_key`, so a compromised update
+//! channel cannot inject patterns or labels. It is then validated as a whole
+//! and replaces the previous one only if every rule compiles; its `version`
+//! must be higher than the loaded one so a stale bundle cannot roll back a
+//! newer one.
+
+use crate::compare::{compare_key, folds_case};
+use crate::{DetectorOptions, Match, PATTERNS, has_overlap};
+use ed25519_dalek::{Signature, VerifyingKey};
+use regex::{Regex, RegexBuilder};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{OnceLock, RwLock};
+
+/// Cap in bytes on each bundled rule's compiled program, so a bundle cannot
+/// make every scan pathologically slow or memory-hungry.
+const RULE_SIZE_LIMIT: usize = 256 * 1024;
+
+#[derive(Deserialize)]
+struct RuleSpec {
+    rule_id: String,
+    #[serde(rename = "type")]
+    type_: String,
+    chain: String,
+    pattern: String,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Bundle {
+    version: u64,
+    #[serde(default)]
+    rules: Vec<RuleSpec>,
+    #[serde(default)]
+    labels: BTreeMap<String, String>,
+    #[serde(default)]
+    promote: Vec<String>,
+    #[serde(default)]
+    demote: Vec<String>,
+}
+
+struct LoadedRule {
+    rule_id: String,
+    type_: String,
+    chain: String,
+    regex: Regex,
+}
+
+#[derive(Default)]
+struct RuleSet {
+    version: u64,
+    /// Newest version ever loaded, kept across `clear_rules` so an older
+    /// signed bundle cannot be replayed after a clear.
+    newest: u64,
+    rules: Vec<LoadedRule>,
+    /// Labels keyed as values of unknown family compare (only `0x` folded).
+    labels: BTreeMap<String, String>,
+    /// The same labels keyed lowercase, for case-insensitive families.
+    folded_labels: BTreeMap<String, String>,
+    promote: Vec<String>,
+    demote: Vec<String>,
+}
+
+static LOADED: OnceLock<RwLock<RuleSet>> = OnceLock::new();
+static TRUSTED_KEYS: RwLock<Vec<VerifyingKey>> = RwLock::new(Vec::new());
+/// Bumped whenever the loaded rules change, so cached results can tell
+/// they were computed under an older rule set.
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+
+fn loaded() -> &'static RwLock<RuleSet> {
+    LOADED.get_or_init(|| RwLock::new(RuleSet::default()))
+}
+
+fn decode_hex<const N: usize>(hex: &str, what: &str) -> Result<[u8; N], String> {
+    let invalid = || format!("{what} must be {} hex characters", N * 2);
+    let hex = hex.trim();
+    if hex.len() != N * 2 || !hex.is_ascii() {
+        return Err(invalid());
+    }
+    let mut bytes = [0; N];
+    for (byte, pair) in bytes.iter_mut().zip(hex.as_bytes().chunks(2)) {
+        let pair = std::str::from_utf8(pair).map_err(|_| invalid())?;
+        *byte = u8::from_str_radix(pair, 16).map_err(|_| invalid())?;
+    }
+    Ok(bytes)
+}
+
+/// Pins an ed25519 public key (hex) whose signatures `load_rules` accepts.
+/// Until a key is pinned, every bundle is refused.
+pub fn trust_rules_key(public_key_hex: &str) -> Result<(), String> {
+    let bytes = decode_hex::<32>(public_key_hex, "Public key")?;
+    let key = VerifyingKey::from_bytes(&bytes).map_err(|_| "Invalid ed25519 public key")?;
+    let mut keys = TRUSTED_KEYS.write().unwrap();
+    if !keys.contains(&key) {
+        keys.push(key);
+    }
+    Ok(())
+}
+
+/// Checks `signature_hex` over the bundle bytes against the pinned keys.
+fn verify_signature(json: &str, signature_hex: &str) -> Result<(), String> {
+    let signature = Signature::from_bytes(&decode_hex::<64>(signature_hex, "Signature")?);
+    let keys = TRUSTED_KEYS.read().unwrap();
+    if keys.is_empty() {
+        return Err("No trusted rules key is pinned".to_string());
+    }
+    keys.iter()
+        .any(|key| key.verify_strict(json.as_bytes(), &signature).is_ok())
+        .then_some(())
+        .ok_or_else(|| "Rules bundle signature does not verify".to_string())
+}
+
+fn compile(spec: RuleSpec) -> Result<LoadedRule, String> {
+    if spec.rule_id.is_empty() || spec.type_.is_empty() || spec.chain.is_empty() {
+        return Err("rule_id, type and chain must be non-empty".to_string());
+    }
+    if PATTERNS.iter().any(|p| p.rule_id == spec.rule_id) {
+        return Err(format!("Rule {} shadows a built-in rule", spec.rule_id));
+    }
+    let regex = RegexBuilder::new(&spec.pattern)
+        .size_limit(RULE_SIZE_LIMIT)
+        .build()
+        .map_err(|e| format!("Rule {} does not compile: {e}", spec.rule_id))?;
+    Ok(LoadedRule {
+        rule_id: spec.rule_id,
+        type_: spec.type_,
+        chain: spec.chain,
+        regex,
+    })
+}
+
+/// Verifies the bundle's signature (hex, over the exact `json` bytes),
+/// validates it and, if it is newer than every bundle loaded before, replaces
+/// the loaded one.
+/// Returns the number of rules now active.
+pub fn load_rules(json: &str, signature_hex: &str) -> Result<usize, String> {
+    verify_signature(json, signature_hex)?;
+    let bundle: Bundle =
+        serde_json::from_str(json).map_err(|e| format!("Invalid rules bundle: {e}"))?;
+    let mut rules: Vec<LoadedRule> = Vec::with_capacity(bundle.rules.len());
+    for spec in bundle.rules {
+        if rules.iter().any(|r| r.rule_id == spec.rule_id) {
+            return Err(format!("Duplicate rule {}", spec.rule_id));
+        }
+        rules.push(compile(spec)?);
+    }
+
+    let labels: BTreeMap<String, String> = bundle
+        .labels
+        .into_iter()
+        .map(|(value, label)| (compare_key("", &value), label))
+        .collect();
+    let folded_labels = labels
+        .iter()
+        .map(|(value, label)| (value.to_ascii_lowercase(), label.clone()))
+        .collect();
+    let count = rules.len();
+    let mut set = loaded().write().unwrap();
+    if bundle.version <= set.newest {
+        return Err(format!(
+            "Rules bundle version {} is not newer than version {}, already loaded",
+            bundle.version, set.newest
+        ));
+    }
+    *set = RuleSet {
+        version: bundle.version,
+        newest: bundle.version,
+        rules,
+        labels,
+        folded_labels,
+        promote: bundle.promote,
+        demote: bundle.demote,
+    };
+    GENERATION.fetch_add(1, Ordering::SeqCst);
+    Ok(count)
+}
+
+/// Version of the loaded bundle; 0 when none is loaded.
+pub fn rules_version() -> u64 {
+    loaded().read().unwrap().version
+}
+
+/// Drops the loaded bundle, returning to the built-in rules only. Bundles
+/// no newer than the dropped one are still refused.
+pub fn clear_rules() {
+    let mut set = loaded().write().unwrap();
+    *set = RuleSet {
+        newest: set.newest,
+        ..RuleSet::default()
+    };
+    GENERATION.fetch_add(1, Ordering::SeqCst);
+}
+
+/// Changes each time `load_rules` or `clear_rules` replaces the rule set.
+pub(crate) fn generation() -> u64 {
+    GENERATION.load(Ordering::SeqCst)
+}
+
+/// The bundle's promote and demote lists, ranked after the caller's own.
+pub(crate) fn priorities() -> (Vec<String>, Vec<String>) {
+    let set = loaded().read().unwrap();
+    (set.promote.clone(), set.demote.clone())
+}
+
+/// Runs the bundled rules after the built-in ones; their hits never
+/// override a span an earlier pattern already claimed. Each match records
+/// its rule's `chain` in metadata, since it has no `Pattern` to look it up in.
+pub(crate) fn scan_loaded(text: &str, options: &DetectorOptions, matches: &mut Vec<Match>) {
+    let set = loaded().read().unwrap();
+    for rule in &set.rules {
+        if options.disabled.contains(&rule.type_) {
+            continue;
+        }
+        for found in rule.regex.find_iter(text) {
+            if found.is_empty() || has_overlap(found.start(), found.end(), matches) {
+                continue;
+            }
+            matches.push(Match {
+                value: found.as_str().to_string(),
+                index: found.start(),
+                type_: rule.type_.clone(),
+                rule_id: rule.rule_id.clone(),
+                compare_key: String::new(),
+                metadata: BTreeMap::from([("chain".to_string(), rule.chain.clone())]),
+                possible_chains: Vec::new(),
+                corrected: false,
+                corrected_value: None,
+                editable: false,
+                positions: Vec::new(),
+                network: None,
+                normalized: None,
+                chain: None,
+                kind: String::new(),
+                variant: None,
+            });
+        }
+    }
+}
+
+/// Attaches the bundle's `label` to every match whose value it names,
+/// compared by `compare_key`.
+pub(crate) fn attach_labels(matches: &mut [Match]) {
+    let set = loaded().read().unwrap();
+    if set.labels.is_empty() {
+        return;
+    }
+    for m in matches {
+        let labels = if folds_case(&m.rule_id) {
+            &set.folded_labels
+        } else {
+            &set.labels
+        };
+        if let Some(label) = labels.get(&m.compare_key) {
+            m.metadata.insert("label".to_string(), label.clone());
+        }
+    }
+}
@@ -0,0 +1,44 @@
+//! Incremental delivery: matches are handed to a callback chunk by chunk,
+//! top of the text first, so a host can start acting on early matches while
+//! the rest of a long text is still being scanned. Chunks are scanned with
+//! the same overlap as `chunked`, so the callback sees what `find_matches`
+//! would return.
+
+use crate::chunked::{chunks, scan_chunk};
+use crate::{DetectorError, DetectorOptions, Match, collect_with, group_and_cap, limited_regexes};
+
+/// Target chunk size.
+const SEGMENT_BYTES: usize = 8 * 1024;
+
+/// Calls `on_match` with each match in text order, chunk by chunk.
+/// Returning `false` from the callback stops the scan early. With
+/// `group_positions` or `max_matches`, which weigh the whole document, the
+/// text is scanned in full before the first match is delivered.
+pub fn for_each_match<F>(
+    text: &str,
+    options: &DetectorOptions,
+    mut on_match: F,
+) -> Result<(), DetectorError>
+where
+    F: FnMut(Match) -> bool,
+{
+    // Fail on size limits before anything is delivered.
+    let limited = limited_regexes(options)?;
+    if options.group_positions || options.max_matches.is_some() {
+        let matches = collect_with(text, options, limited.as_deref());
+        for m in group_and_cap(matches, options).matches {
+            if !on_match(m) {
+                return Ok(());
+            }
+        }
+        return Ok(());
+    }
+    for chunk in chunks(text, SEGMENT_BYTES) {
+        for m in scan_chunk(text, &chunk, options, limited.as_deref()) {
+            if !on_match(m) {
+                return Ok(());
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,99 @@
+//! Structured labels derived from each match's rule id, so hosts can filter
+//! on `chain`, `kind` and `variant` instead of prefix-matching `type_`
+//! strings. Rule ids read `family.sub_kind[.qualifier]`
+//! (`bitcoin.segwit_address`, `evm.tx_hash.truncated`); the family gives the
+//! chain, the sub-kind the kind and variant. `Match::network` keeps naming
+//! the network within a chain (`mainnet`, `testnet`).
+
+use crate::{Match, PATTERNS};
+
+/// Chains of families that only arise from relabeling, with no pattern of
+/// their own to look the chain up in.
+const RELABELED_CHAINS: &[(&str, &str)] = &[("aptos", "aptos"), ("sui", "sui")];
+
+/// Words ending a sub-kind that name the kind rather than a variant.
+const KIND_WORDS: &[&str] = &["address", "account", "signature", "hash", "url"];
+
+/// Sub-kinds that are a kind on their own, with no variant.
+const PLAIN_SUB_KINDS: &[&str] = &[
+    "address",
+    "account",
+    "name",
+    "chain_id",
+    "inscription",
+    "wallet",
+    "ambiguous",
+];
+
+fn chain(m: &Match) -> Option<String> {
+    if let Some(chain) = m.metadata.get("chain") {
+        return Some(chain.clone());
+    }
+    let family = m.rule_id.split('.').next().unwrap_or_default();
+    PATTERNS
+        .iter()
+        .find(|p| p.rule_id == m.rule_id)
+        .or_else(|| {
+            PATTERNS
+                .iter()
+                .find(|p| p.rule_id.split('.').next() == Some(family))
+        })
+        .map(|p| p.chain)
+        .or_else(|| {
+            RELABELED_CHAINS
+                .iter()
+                .find(|(f, _)| *f == family)
+                .map(|(_, c)| *c)
+        })
+        .map(str::to_string)
+}
+
+fn kind(rule_id: &str, sub_kind: &str) -> &'static str {
+    match sub_kind {
+        _ if sub_kind.starts_with("tx_") => "tx",
+        "name" | "namehash" => "name",
+        _ if rule_id == "near.account" => "name",
+        "chain_id" => "chain_id",
+        "inscription" => "inscription",
+        "wallet" if rule_id.starts_with("deeplink.") => "link",
+        "gateway_url" => "link",
+        "ambiguous" => "ambiguous",
+        _ if rule_id.starts_with("pii.") => "pii",
+        _ => "address",
+    }
+}
+
+fn variant(sub_kind: &str, qualifier: Option<&str>) -> Option<String> {
+    if qualifier == Some("truncated") {
+        return Some("truncated".to_string());
+    }
+    let own = if sub_kind.starts_with("tx_") || PLAIN_SUB_KINDS.contains(&sub_kind) {
+        None
+    } else {
+        KIND_WORDS
+            .iter()
+            .find_map(|word| sub_kind.strip_suffix(word)?.strip_suffix('_'))
+            .or(Some(sub_kind))
+    };
+    own.or(qualifier).map(str::to_string)
+}
+
+fn sub_kind_and_qualifier(rule_id: &str) -> (&str, Option<&str>) {
+    let mut parts = rule_id.splitn(3, '.').skip(1);
+    (parts.next().unwrap_or_default(), parts.next())
+}
+
+/// The kind `describe` gives a rule id, for passes that run before it.
+pub(crate) fn kind_of(rule_id: &str) -> &'static str {
+    kind(rule_id, sub_kind_and_qualifier(rule_id).0)
+}
+
+/// Fills `chain`, `kind` and `variant` on every match from its final rule id.
+pub(crate) fn describe(matches: &mut [Match]) {
+    for m in matches.iter_mut() {
+        let (sub_kind, qualifier) = sub_kind_and_qualifier(&m.rule_id);
+        m.chain = chain(m);
+        m.kind = kind(&m.rule_id, sub_kind).to_string();
+        m.variant = variant(sub_kind, qualifier);
+    }
+}
@@ -0,0 +1,31 @@
+//! Scan counters and histograms reported through the `metrics` facade.
+//! Nothing is exported until the host installs a recorder, e.g.
+//! `metrics-exporter-prometheus`.
+
+use crate::Match;
+use metrics::{Unit, counter, describe_counter, describe_histogram, histogram};
+use std::time::Duration;
+
+const SCANS: &str = "wallet_mask_scans_total";
+const MATCHES: &str = "wallet_mask_matches_total";
+const BYTES: &str = "wallet_mask_bytes_scanned_total";
+const DURATION: &str = "wallet_mask_scan_duration_seconds";
+
+/// Registers units and help text for every metric this crate emits. Call
+/// once after installing the recorder.
+pub fn describe_metrics() {
+    describe_counter!(SCANS, "Scans performed");
+    describe_counter!(MATCHES, "Matches reported, labelled by type");
+    describe_counter!(BYTES, Unit::Bytes, "Bytes of text scanned");
+    describe_histogram!(DURATION, Unit::Seconds, "Time spent per scan");
+}
+
+/// Records one completed scan.
+pub(crate) fn record_scan(text_len: usize, matches: &[Match], elapsed: Duration) {
+    counter!(SCANS).increment(1);
+    counter!(BYTES).increment(text_len as u64);
+    histogram!(DURATION).record(elapsed.as_secs_f64());
+    for m in matches {
+        counter!(MATCHES, "type" => m.type_.clone()).increment(1);
+    }
+}
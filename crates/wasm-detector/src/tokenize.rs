@@ -0,0 +1,121 @@
+//! Candidate extraction by token. The text is split on whitespace and
+//! punctuation that never occurs inside a value, and also where ASCII meets
+//! another script (`钱包0x…`), which `\b` alone does not treat as a boundary.
+//! Each pattern then runs only over tokens at least as long as its shortest
+//! possible match, so most of the text is skipped for long-form patterns.
+//!
+//! Patterns whose regex can consume a split character (`chainId: 137`, deep
+//! links with query strings) keep scanning the whole text.
+
+use crate::{PATTERNS, Pattern};
+use regex::Regex;
+use regex_syntax::hir::{Class, Hir, HirKind};
+use std::ops::Range;
+use std::sync::OnceLock;
+
+/// Whether a token ends before `c`, regardless of the previous character.
+fn is_split(c: char) -> bool {
+    c.is_whitespace()
+        || matches!(
+            c,
+            ',' | ';'
+                | '!'
+                | '"'
+                | '\''
+                | '`'
+                | '('
+                | ')'
+                | '['
+                | ']'
+                | '{'
+                | '}'
+                | '<'
+                | '>'
+                | '|'
+        )
+        || (!c.is_ascii() && !c.is_alphanumeric() && c != '…')
+}
+
+/// Byte ranges of the tokens in `text`.
+pub(crate) fn tokenize(text: &str) -> Vec<Range<usize>> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+    let mut prev: Option<char> = None;
+
+    for (i, c) in text.char_indices() {
+        let script_change = prev.is_some_and(|p| {
+            p.is_alphanumeric() && c.is_alphanumeric() && p.is_ascii() != c.is_ascii()
+        });
+        if (is_split(c) || script_change)
+            && let Some(s) = start.take()
+        {
+            tokens.push(s..i);
+        }
+        if !is_split(c) && start.is_none() {
+            start = Some(i);
+        }
+        prev = Some(c);
+    }
+    if let Some(s) = start {
+        tokens.push(s..text.len());
+    }
+    tokens
+}
+
+/// Whether any string the regex can match contains a split character.
+fn can_span_tokens(hir: &Hir) -> bool {
+    match hir.kind() {
+        HirKind::Empty | HirKind::Look(_) => false,
+        HirKind::Literal(lit) => String::from_utf8_lossy(&lit.0).chars().any(is_split),
+        HirKind::Class(Class::Unicode(class)) => class
+            .ranges()
+            .iter()
+            .any(|r| (r.start()..=r.end()).any(is_split)),
+        HirKind::Class(Class::Bytes(class)) => class
+            .ranges()
+            .iter()
+            .any(|r| (r.start()..=r.end()).any(|b| !b.is_ascii() || is_split(b as char))),
+        HirKind::Repetition(rep) => can_span_tokens(&rep.sub),
+        HirKind::Capture(cap) => can_span_tokens(&cap.sub),
+        HirKind::Concat(subs) | HirKind::Alternation(subs) => subs.iter().any(can_span_tokens),
+    }
+}
+
+static TOKEN_MIN_LEN: OnceLock<Vec<OnceLock<Option<usize>>>> = OnceLock::new();
+
+/// Shortest match of a token-scoped pattern, or `None` when the pattern may
+/// span tokens and must scan the whole text.
+fn token_min_len(pattern: &Pattern) -> Option<usize> {
+    let table = TOKEN_MIN_LEN.get_or_init(|| PATTERNS.iter().map(|_| OnceLock::new()).collect());
+    *table[pattern.position()].get_or_init(|| {
+        let hir = regex_syntax::parse(pattern.source).ok()?;
+        if can_span_tokens(&hir) {
+            return None;
+        }
+        Some(hir.properties().minimum_len().unwrap_or(0))
+    })
+}
+
+/// Regex hits of `pattern` in `text` as `(byte offset, value)`, found
+/// within tokens when the pattern allows it.
+pub(crate) fn candidates<'t>(
+    text: &'t str,
+    tokens: &[Range<usize>],
+    pattern: &Pattern,
+    re: &Regex,
+) -> Vec<(usize, &'t str)> {
+    let Some(min_len) = token_min_len(pattern) else {
+        return re
+            .find_iter(text)
+            .map(|m| (m.start(), m.as_str()))
+            .collect();
+    };
+    tokens
+        .iter()
+        .filter(|token| token.len() >= min_len)
+        .flat_map(|token| {
+            re.find_iter(&text[token.clone()])
+                .map(move |m| (token.start + m.start(), m.as_str()))
+        })
+        .collect()
+}
@@ -0,0 +1,44 @@
+use crate::Match;
+use crate::explain::Rejection;
+use std::sync::{OnceLock, RwLock};
+
+/// Post-match hook for Rust embedders. Runs on every candidate that passed
+/// the built-in checks, before it claims its span, and may veto the match or
+/// enrich it through `Match::metadata` (e.g. a label from a database lookup).
+pub trait MatchValidator: Send + Sync {
+    /// Short identifier reported by `explain` when this validator rejects a candidate.
+    fn name(&self) -> &str;
+
+    /// Returns `false` to drop the match.
+    fn validate(&self, m: &mut Match) -> bool;
+}
+
+type Registry = RwLock<Vec<Box<dyn MatchValidator>>>;
+
+static VALIDATORS: OnceLock<Registry> = OnceLock::new();
+
+fn validators() -> &'static Registry {
+    VALIDATORS.get_or_init(|| RwLock::new(Vec::new()))
+}
+
+/// Appends a validator to the global pipeline; validators run in registration order.
+pub fn register_validator(validator: Box<dyn MatchValidator>) {
+    validators().write().unwrap().push(validator);
+}
+
+/// Removes every registered validator.
+pub fn clear_validators() {
+    validators().write().unwrap().clear();
+}
+
+/// Runs the registered validators, stopping at the first one that vetoes the match.
+pub(crate) fn run_validators(m: &mut Match) -> Result<(), Rejection> {
+    for validator in validators().read().unwrap().iter() {
+        if !validator.validate(m) {
+            return Err(Rejection::Vetoed {
+                validator: validator.name().to_string(),
+            });
+        }
+    }
+    Ok(())
+}
@@ -80,15 +80,8 @@ function createBlurSpan(text) {
     return span;
 }
 
-function processTextNode(textNode) {
-    if (!wasm || shouldSkipNode(textNode)) return;
-
+function applyMatches(textNode, matches) {
     const text = textNode.textContent;
-
-    const matches = wasm.find_matches(text);
-
-    if (matches.length === 0) return;
-
     const fragment = document.createDocumentFragment();
     let lastIndex = 0;
 
@@ -109,15 +102,34 @@ function processTextNode(textNode) {
     textNode.parentNode.replaceChild(fragment, textNode);
 }
 
-function processNode(node) {
+function collectTextNodes(node, textNodes) {
     if (node.nodeType === Node.TEXT_NODE) {
-        processTextNode(node);
+        if (!shouldSkipNode(node)) textNodes.push(node);
     } else if (node.nodeType === Node.ELEMENT_NODE) {
         if (["SCRIPT", "STYLE", "NOSCRIPT", "IFRAME", "SVG"].includes(node.tagName))
             return;
         if (node.hasAttribute(PROCESSED_ATTR)) return;
 
-        Array.from(node.childNodes).forEach(processNode);
+        node.childNodes.forEach((child) => collectTextNodes(child, textNodes));
+    }
+}
+
+// Serializes the whole subtree and scans it in a single wasm call.
+function processNode(node) {
+    if (!wasm) return;
+
+    const textNodes = [];
+    collectTextNodes(node, textNodes);
+    if (textNodes.length === 0) return;
+
+    const batch = textNodes.map((textNode, nodeId) => ({
+        nodeId,
+        text: textNode.textContent,
+    }));
+    const results = wasm.find_matches_batch_for_host(batch, location.hostname);
+
+    for (const [nodeId, matches] of Object.entries(results)) {
+        applyMatches(textNodes[Number(nodeId)], matches);
     }
 }
 